@@ -0,0 +1,89 @@
+use crate::{
+    error::Error,
+    state::{State, WatchMode},
+    view::View
+};
+
+use eframe::{
+    egui::{
+        Ui,
+        TextureId,
+        Rect,
+        Pos2,
+        Color32
+    }
+};
+
+use chobitlibs::chobit_ani_value::ChobitAniValue;
+
+/// Shows AM or PM in clock mode.
+pub struct AmPmView {
+    depth: i32,
+
+    texture_id: TextureId,
+
+    region: Rect,
+    ani_value: ChobitAniValue
+}
+
+const AM_FRAME: usize = 0;
+const PM_FRAME: usize = 1;
+
+impl AmPmView {
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(
+        depth: i32,
+        texture_id: TextureId,
+        region: Rect
+    ) -> Self {
+        Self {
+            depth: depth,
+
+            texture_id: texture_id,
+
+            region: region,
+            ani_value: ChobitAniValue::new(2, &[2], 1.0).expect(
+                "Error at AmPmView::new()"
+            )
+        }
+    }
+}
+
+impl View for AmPmView {
+    #[inline]
+    fn depth(&self) -> i32 {self.depth}
+
+    fn ready(&mut self, _ui: &Ui, _state: &mut State) -> Result<(), Error> {
+        // nothing to do.
+        Ok(())
+    }
+
+    fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
+        if state.watch_mode != WatchMode::Clock {
+            return Ok(());
+        }
+
+        let frame = if state.clock_is_pm() {PM_FRAME} else {AM_FRAME};
+
+        self.ani_value.set_frame(frame);
+        let (left, top, right, bottom) =
+            self.ani_value.uv_frame_left_top_right_bottom();
+
+        ui.painter().image(
+            self.texture_id,
+            self.region,
+            Rect::from_min_max(
+                Pos2::new(*left, *top),
+                Pos2::new(*right, *bottom)
+            ),
+            Color32::WHITE
+        );
+
+        Ok(())
+    }
+
+    fn init(&mut self, _state: &mut State) -> Result<(), Error> {
+        // pass
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{error::Error, state::State, view::View};
+use crate::{error::Error, state::{State, AlarmState}, view::View};
 
 use eframe::{
     egui::{
@@ -31,17 +31,20 @@ pub struct AnimationView {
     do_animation: bool,
 
     probability: Option<f64>,
+    on_alarm: bool,
     rng: Rc<RefCell<ChobitRand>>,
     acc_time: f32
 }
 
 impl AnimationView {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(
         depth: i32,
         texture_id: TextureId,
         region: Rect,
         mut ani_value: ChobitAniValue,
         probability: Option<f32>,
+        on_alarm: bool,
         rng: Rc<RefCell<ChobitRand>>
     ) -> Self {
         ani_value.set_frame(0);
@@ -57,6 +60,7 @@ impl AnimationView {
             do_animation: false,
 
             probability: probability.map(|value| value as f64),
+            on_alarm: on_alarm,
             rng: rng,
             acc_time: 0.0
         }
@@ -68,6 +72,17 @@ impl View for AnimationView {
     fn depth(&self) -> i32 {self.depth}
 
     fn ready(&mut self, _ui: &Ui, state: &mut State) -> Result<(), Error> {
+        // plays only while an alarm is ringing.
+        if self.on_alarm {
+            if let AlarmState::Ringing(..) = state.alarm_state {
+                self.ani_value.elapse(state.dt);
+            } else {
+                self.ani_value.set_frame(0);
+            }
+
+            return Ok(());
+        }
+
         match self.probability {  // running animation.
             Some(probability) => if self.do_animation {
                 let current_frame = self.ani_value.current_frame();
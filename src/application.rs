@@ -2,9 +2,12 @@ use crate::{
     error::Error,
     state::*,
     settings::Settings,
-    settings_loader::SettingsLoader,
-    save_data::SaveData,
+    settings_loader::{SettingsLoader, LoadProblems, DefaultSkinIds},
+    time_zone::ClockZone,
+    save_data::{SaveData, SavedAlarmState},
     skin::Skin,
+    key_bindings::KeyBindings,
+    settings_watcher::SettingsWatcher,
     model::Model
 };
 
@@ -21,20 +24,26 @@ use eframe::{
         TextureHandle,
         Color32,
         CentralPanel,
+        Id,
+        Sense,
+        Button as GuiButton,
         Frame as GuiFrame
     }
 };
 
 use getopts::Options;
 
+use chrono::Utc;
+
 use std::{
     fmt,
     path::{Path, PathBuf},
     fs::OpenOptions,
-    io::{BufReader, Write, BufWriter},
+    io::{self, BufReader},
     rc::Rc,
     cell::RefCell,
-    process::ExitCode
+    process::{Child, Command, ExitCode},
+    time::{Duration, Instant}
 };
 
 use chobitlibs::{
@@ -47,7 +56,8 @@ pub enum ApplicationError {
     CommandOptionError(String),
     OnlyShowHelp(String),
     CouldNotConvertPath(String),
-    CouldNotParseSettingsFile
+    InvalidSettings(Vec<String>),
+    InvalidOptionValue {option: String, value: String}
 }
 
 impl fmt::Display for ApplicationError {
@@ -79,10 +89,20 @@ impl fmt::Display for ApplicationError {
                 )?;
             },
 
-            Self::CouldNotParseSettingsFile => {
+            Self::InvalidSettings(problems) => {
+                write!(
+                    formatter,
+                    r#""InvalidSettings", "problems": {:?}"#,
+                    problems
+                )?;
+            },
+
+            Self::InvalidOptionValue {option, value} => {
                 write!(
                     formatter,
-                    r#""CouldNotParseSettingsFile""#
+                    r#""InvalidOptionValue", "option": {:?}, "value": {:?}"#,
+                    option,
+                    value
                 )?;
             },
         }
@@ -91,64 +111,114 @@ impl fmt::Display for ApplicationError {
     }
 }
 
+/// Every part of settings file except save data.
+pub struct LoadedSettings {
+    settings: Settings,
+    window_size: Vec2,
+    textures: ChobitMap<TextureHandle>,
+    skins: ChobitMap<Rc<RefCell<Skin>>>,
+    skin_names: ChobitMap<String>,
+    key_bindings: KeyBindings,
+    default_mode: WatchMode,
+    clock_format: ClockFormat,
+    clock_zone: ClockZone,
+    stopwatch_limit: WatchTime,
+    stopwatch_overflow: OverflowPolicy,
+    timer_duration: Option<WatchTime>,
+    timer_phases: Vec<TimerPhase>,
+    default_skin_ids: DefaultSkinIds,
+    autosave_interval: Option<Duration>,
+    stopwatch_events: Vec<SkinSwitchEvent>,
+    timer_events: Vec<SkinSwitchEvent>,
+    clock_events: Vec<SkinSwitchEvent>,
+    alarms: Vec<Alarm>,
+    alarm_snooze: Duration,
+    alarm_sound_command: Vec<String>
+}
+
 pub struct Application {
     state: State,
     window_size: Vec2,
     _textures: ChobitMap<TextureHandle>,
     skins: ChobitMap<Rc<RefCell<Skin>>>,
+    skin_names: ChobitMap<String>,
     current_skin: Rc<RefCell<Skin>>,
+    key_bindings: KeyBindings,
     model: Model,
+    save_data_buf: Vec<u8>,
+    settings_file_name: String,
+    settings_watcher: Option<SettingsWatcher>,
+    reload_error: Option<String>,
+    alarm_sound_command: Vec<String>,
+    alarm_sound: Option<Child>,
 
     exit_code: Rc<RefCell<ExitCode>>
 }
 
 impl Application {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(
         args: &[String],
         cc: &CreationContext,
         exit_code: Rc<RefCell<ExitCode>>
     ) -> Result<Self, Error> {
-        let (mut state, window_size, textures, skins) =
-            Self::load_settings(args, cc)?;
+        let (settings_file_name, watch) = Self::parse_args(args)?;
+
+        let loaded =
+            Self::load_settings_or_error(&cc.egui_ctx, &settings_file_name)?;
+
+        let mut state = Self::settings_to_state(
+            &loaded,
+            Self::get_root_dir(&settings_file_name)?
+        )?;
 
         let mut model = Model::new();
         model.init(&mut state)?;
 
-        let current_skin = skins.get(state.current_skin_id).ok_or_else(
-            || Error::NoSkin {id: state.current_skin_id}
-        )?.clone();
+        let current_skin = Self::find_skin(
+            &loaded.skins,
+            &loaded.skin_names,
+            state.current_skin_id
+        )?;
 
         state.init();
 
+        let settings_watcher = watch.then(|| SettingsWatcher::new(
+            &settings_file_name,
+            &loaded.settings,
+            state.now
+        ));
+
         Ok(Self {
             state: state,
-            window_size: window_size,
-            _textures: textures,
-            skins: skins,
+            window_size: loaded.window_size,
+            _textures: loaded.textures,
+            skins: loaded.skins,
+            skin_names: loaded.skin_names,
             current_skin: current_skin,
+            key_bindings: loaded.key_bindings,
             model: model,
+            save_data_buf: Vec::<u8>::new(),
+            settings_file_name: settings_file_name,
+            settings_watcher: settings_watcher,
+            reload_error: None,
+            alarm_sound_command: loaded.alarm_sound_command,
+            alarm_sound: None,
 
             exit_code: exit_code
         })
     }
 
-    fn load_settings(
-        args: &[String],
-        cc: &CreationContext
-    ) -> Result<(
-        State,
-        Vec2,
-        ChobitMap<TextureHandle>,
-        ChobitMap<Rc<RefCell<Skin>>>,
-    ), Error> {
+    // Returns settings file name and whether `--watch` is specified.
+    fn parse_args(args: &[String]) -> Result<(String, bool), Error> {
         let opts = Self::gen_options();
 
-        let matches = opts.parse(&args[1..]).or_else(
-            |_| Err(Error::from(
+        let matches = opts.parse(&args[1..]).map_err(
+            |_| Error::from(
                 ApplicationError::CommandOptionError(
                     Self::gen_usage(&opts, &args[0])
                 )
-            ))
+            )
         )?;
 
         if matches.opt_present("h") {
@@ -157,7 +227,7 @@ impl Application {
             )));
         }
 
-        let settings_file_name: &String = matches.free.get(0).ok_or_else(
+        let settings_file_name = matches.free.first().cloned().ok_or_else(
             || Error::from(
                 ApplicationError::CommandOptionError(
                     Self::gen_usage(&opts, &args[0])
@@ -165,45 +235,277 @@ impl Application {
             )
         )?;
 
-        let reader = BufReader::new(
-            OpenOptions::new().read(true).open(&settings_file_name)?
-        );
+        Ok((settings_file_name, matches.opt_present("w")))
+    }
+
+    /// Loads every part of settings file.
+    ///
+    /// Startup, reloading and `--check` all load settings with this.
+    /// Returns `None` if any problem is found.
+    pub fn load_settings(
+        ctx: &Context,
+        settings_file_name: &str,
+        problems: &mut LoadProblems
+    ) -> Option<LoadedSettings> {
+        let settings = problems.check(
+            "settings file",
+            Self::read_settings_file(settings_file_name)
+        )?;
 
-        let settings: Settings = serde_yaml::from_reader(reader).or_else(
-            |_| Err(Error::from(ApplicationError::CouldNotParseSettingsFile))
+        let root_dir = problems.check(
+            "settings file",
+            Self::get_root_dir(settings_file_name)
         )?;
 
-        let root_dir = Self::get_root_dir(&settings_file_name)?;
+        Self::load_settings_from(ctx, settings, root_dir, problems)
+    }
 
-        let window_size =
-            SettingsLoader::load_window_size(&settings.window_size)?;
+    pub fn read_settings_file(
+        settings_file_name: &str
+    ) -> Result<Settings, Error> {
+        let reader = BufReader::new(
+            OpenOptions::new().read(true).open(settings_file_name)?
+        );
+
+        Ok(serde_yaml::from_reader(reader)?)
+    }
+
+    /// Same as `load_settings()` for already parsed settings.
+    #[allow(clippy::redundant_field_names)]
+    pub fn load_settings_from(
+        ctx: &Context,
+        settings: Settings,
+        root_dir: &Path,
+        problems: &mut LoadProblems
+    ) -> Option<LoadedSettings> {
+        let window_size = problems.check(
+            "window_size",
+            SettingsLoader::load_window_size(&settings.window_size)
+        );
 
         let textures = SettingsLoader::load_textures(
-            &cc.egui_ctx,
+            ctx,
             &root_dir,
-            &settings.textures
-        )?;
+            &settings.textures,
+            problems
+        );
+
+        let (skins, skin_names) =
+            SettingsLoader::load_skins(&settings.skins, &textures, problems);
+
+        SettingsLoader::check_skin_names(&settings, &skin_names, problems);
+
+        let default_mode = problems.check(
+            "default_mode",
+            SettingsLoader::load_default_mode(&settings.default_mode)
+        );
+
+        let clock_format = problems.check(
+            "clock_format",
+            SettingsLoader::load_clock_format(&settings.clock_format)
+        );
+
+        let clock_zone = problems.check(
+            "clock_zone",
+            SettingsLoader::load_clock_zone(&settings.clock_zone)
+        );
+
+        let stopwatch_limit = problems.check(
+            "stopwatch_limit",
+            SettingsLoader::load_stopwatch_limit(&settings.stopwatch_limit)
+        );
+
+        let stopwatch_overflow = problems.check(
+            "stopwatch_overflow",
+            SettingsLoader::load_stopwatch_overflow(
+                &settings.stopwatch_overflow
+            )
+        );
+
+        let timer_settings = problems.check(
+            "timer_duration, timer_phases",
+            SettingsLoader::load_timer_settings(&settings)
+        );
+
+        let default_skin_ids = match timer_settings.as_ref() {
+            Some((timer_duration, _)) => problems.check(
+                "default_timer_skin_name",
+                SettingsLoader::load_default_skin_ids(
+                    &settings,
+                    timer_duration
+                )
+            ),
+
+            None => None
+        };
+
+        let autosave_interval = problems.check(
+            "autosave_interval",
+            SettingsLoader::load_autosave_interval(&settings.autosave_interval)
+        );
+
+        let stopwatch_events = problems.check(
+            "stopwatch_events",
+            SettingsLoader::load_stopwatch_events(&settings.stopwatch_events)
+        );
+
+        let timer_events = problems.check(
+            "timer_events",
+            SettingsLoader::load_timer_events(&settings.timer_events)
+        );
+
+        let clock_events = problems.check(
+            "clock_events",
+            SettingsLoader::load_clock_events(&settings.clock_events)
+        );
+
+        let key_bindings = problems.check(
+            "key_bindings",
+            SettingsLoader::load_key_bindings(&settings.key_bindings)
+        );
+
+        let alarms = problems.check(
+            "alarms",
+            SettingsLoader::load_alarms(&root_dir, &settings.alarms)
+        );
+
+        let alarm_snooze = problems.check(
+            "alarm_snooze",
+            SettingsLoader::load_alarm_snooze(&settings.alarm_snooze)
+        );
+
+        let alarm_sound_command = problems.check(
+            "alarm_sound_command",
+            SettingsLoader::load_alarm_sound_command(
+                &settings.alarm_sound_command,
+                &settings.alarms
+            )
+        );
+
+        // textures and skins report their problems without failing.
+        if !problems.is_empty() {
+            return None;
+        }
+
+        let (timer_duration, timer_phases) = timer_settings?;
+
+        Some(LoadedSettings {
+            settings: settings,
+            window_size: window_size?,
+            textures: textures,
+            skins: skins,
+            skin_names: skin_names,
+            key_bindings: key_bindings?,
+            default_mode: default_mode?,
+            clock_format: clock_format?,
+            clock_zone: clock_zone?,
+            stopwatch_limit: stopwatch_limit?,
+            stopwatch_overflow: stopwatch_overflow?,
+            timer_duration: timer_duration,
+            timer_phases: timer_phases,
+            default_skin_ids: default_skin_ids?,
+            autosave_interval: autosave_interval?,
+            stopwatch_events: stopwatch_events?,
+            timer_events: timer_events?,
+            clock_events: clock_events?,
+            alarms: alarms?,
+            alarm_snooze: alarm_snooze?,
+            alarm_sound_command: alarm_sound_command?
+        })
+    }
+
+    fn load_settings_or_error(
+        ctx: &Context,
+        settings_file_name: &str
+    ) -> Result<LoadedSettings, Error> {
+        let mut problems = LoadProblems::new();
+
+        Self::load_settings(ctx, settings_file_name, &mut problems).ok_or_else(
+            || Error::from(
+                ApplicationError::InvalidSettings(problems.into_vec())
+            )
+        )
+    }
+
+    /// Loads settings file again and replaces skins and settings.
+    ///
+    /// Times, laps and watch mode are kept.
+    /// If loading fails, nothing is replaced.
+    fn reload_settings(&mut self, ctx: &Context) -> Result<(), Error> {
+        let loaded =
+            Self::load_settings_or_error(ctx, &self.settings_file_name)?;
 
-        let skins = SettingsLoader::load_skins(&settings.skins, &textures)?;
+        let mut state = self.state.clone();
+        Self::apply_settings_to_state(&mut state, &loaded);
 
-        let state = Self::settings_to_state(
-            &settings,
-            root_dir
+        // the request is cleared by `init()` before `update_core()` sees it.
+        let alarm_removed = state.alarm_stop_request;
+
+        state.init();
+        self.model.init(&mut state)?;
+
+        let current_skin = Self::find_skin(
+            &loaded.skins,
+            &loaded.skin_names,
+            state.current_skin_id
         )?;
 
-        Ok((state, window_size, textures, skins))
+        if let Some(settings_watcher) = self.settings_watcher.as_mut() {
+            settings_watcher.set_files(
+                &self.settings_file_name,
+                &loaded.settings
+            );
+        }
+
+        self.state = state;
+        self.window_size = loaded.window_size;
+        self._textures = loaded.textures;
+        self.skins = loaded.skins;
+        self.skin_names = loaded.skin_names;
+        self.current_skin = current_skin;
+        self.key_bindings = loaded.key_bindings;
+        self.alarm_sound_command = loaded.alarm_sound_command;
+
+        if alarm_removed {
+            self.stop_alarm_sound();
+        }
+
+        Ok(())
+    }
+
+    fn find_skin(
+        skins: &ChobitMap<Rc<RefCell<Skin>>>,
+        skin_names: &ChobitMap<String>,
+        skin_id: u64
+    ) -> Result<Rc<RefCell<Skin>>, Error> {
+        skins.get(skin_id).cloned().ok_or_else(|| Error::NoSkin {
+            name: match skin_names.get(skin_id) {
+                Some(name) => name.clone(),
+                None => format!("(ID: {})", skin_id)
+            }
+        })
     }
 
-    fn gen_options() -> Options {
+    pub fn gen_options() -> Options {
         let mut ret = Options::new();
 
         ret.optflag("h", "help", "print usage");
+        ret.optflag(
+            "",
+            "check",
+            "check settings file and print problems without opening window"
+        );
+        ret.optflag(
+            "w",
+            "watch",
+            "reload settings file and textures when they are modified"
+        );
 
         ret
     }
 
     #[inline]
-    fn gen_usage(opts: &Options, program_name: &str) -> String {
+    pub fn gen_usage(opts: &Options, program_name: &str) -> String {
         opts.usage(format!(
             "{} SETTINGS_FILE",
             program_name
@@ -211,9 +513,9 @@ impl Application {
     }
 
     #[inline]
-    fn get_root_dir<'a>(
-        settings_file_name: &'a str
-    ) -> Result<&'a Path, Error> {
+    pub fn get_root_dir(
+        settings_file_name: &str
+    ) -> Result<&Path, Error> {
         Path::new(settings_file_name).parent().ok_or_else(
             || Error::from(ApplicationError::CouldNotConvertPath(
                 settings_file_name.to_string()
@@ -221,69 +523,289 @@ impl Application {
         )
     }
 
+    #[allow(clippy::redundant_field_names)]
     fn settings_to_state(
-        settings: &Settings,
+        loaded: &LoadedSettings,
         root_dir: &Path
     ) -> Result<State, Error> {
+        let settings = &loaded.settings;
+
         let save_data_path = PathBuf::from(&settings.save_data_file);
 
-        let (
-            watch_mode,
-            stopwatch_time,
-            saved_time,
-        ) = match SettingsLoader::load_save_data_file(
+        let mut timer_duration = loaded.timer_duration.clone();
+        let timer_phases = loaded.timer_phases.clone();
+
+        let save_data = match SettingsLoader::load_save_data_file(
             &root_dir,
             &save_data_path
         ) {
-            Ok(SaveData {
-                watch_mode,
-                stopwatch_time,
-                saved_time,
-                ..
-            }) => (watch_mode, stopwatch_time, saved_time),
-
-            Err(..) => (
-                SettingsLoader::load_default_mode(
-                    &settings.default_mode
-                )?,
-                WatchTime::default(),
-                0.0
-            )
+            Ok(save_data) => save_data,
+
+            Err(..) => SaveData::new(loaded.default_mode)
+        };
+
+        let SaveData {
+            mut watch_mode,
+            mut stopwatch_elapsed,
+            stopwatch_running,
+            laps,
+            timer_remaining,
+            timer_phase,
+            mut skin_overrides,
+            alarm_state: saved_alarm_state,
+            ..
+        } = save_data;
+
+        let resume_running = match (stopwatch_running, watch_mode) {
+            (Some(saved_at), WatchMode::Stopwatch(..))
+                if settings.resume_running =>
+            {
+                // counts the time the application was closed in.
+                stopwatch_elapsed +=
+                    (Utc::now() - saved_at).to_std().unwrap_or_default();
+                watch_mode = WatchMode::Stopwatch(StopwatchMode::Running);
+
+                true
+            },
+
+            _ => false
         };
 
+        // phases may have been removed from settings file.
+        let (timer_phase, timer_remaining) =
+            match timer_phases.get(timer_phase) {
+                Some(phase) => {
+                    timer_duration = Some(phase.duration.clone());
+
+                    (timer_phase, timer_remaining)
+                },
+
+                None if timer_phase == 0 => (0, timer_remaining),
+
+                None => (0, None)
+            };
+
+        let timer_total = timer_duration.as_ref().map(
+            |duration| duration.to_duration()
+        ).unwrap_or_default();
+
+        let timer_elapsed = match timer_remaining {
+            Some(remaining) => timer_total.saturating_sub(remaining),
+            None => Duration::ZERO
+        };
+
+        if let WatchMode::Timer(..) = watch_mode {
+            watch_mode = if timer_duration.is_none() {
+                WatchMode::Clock
+            } else if timer_elapsed >= timer_total {
+                WatchMode::Timer(TimerMode::Expired)
+            } else {
+                WatchMode::Timer(TimerMode::Stopped)
+            };
+        }
+
+        let default_skin_ids = loaded.default_skin_ids;
+
         let save_data_path = root_dir.join(&save_data_path);
 
-        Ok(State {
+        let skin_ids = Self::load_skin_ids(settings);
+
+        // skins may have been removed from settings file.
+        skin_overrides.retain(&skin_ids);
+
+        let now = Instant::now();
+
+        let alarms = loaded.alarms.clone();
+
+        let mut alarm_state = Self::to_alarm_state(saved_alarm_state, now);
+
+        // alarms may have been removed from settings file.
+        if alarm_state.index().map(|index| index >= alarms.len())
+            .unwrap_or(false)
+        {
+            alarm_state = AlarmState::Idle;
+        }
+
+        let mut state = State {
             watch_mode: watch_mode,
             clicked_btn: None,
             dt: Default::default(),
+            now: now,
             button_is_pressed: false,
 
             quit_request: false,
+            save_request: false,
+            minimize_request: false,
+            reload_request: false,
+
+            always_on_top: true,
 
-            current_stopwatch_time: stopwatch_time,
+            current_stopwatch_time: Default::default(),
+            current_timer_time: Default::default(),
             current_clock_time: Default::default(),
+            current_date: Default::default(),
+            current_utc: Default::default(),
+            clock_format: loaded.clock_format,
+            clock_zone: loaded.clock_zone.clone(),
 
             change_skin_request: false,
             current_skin_id: Default::default(),
 
-            saved_time: saved_time,
+            skin_ids: skin_ids,
+            skin_overrides: skin_overrides,
+
+            stopwatch_elapsed: ElapsedTime::new(stopwatch_elapsed),
+            stopwatch_limit: loaded.stopwatch_limit.clone(),
+            stopwatch_overflow: loaded.stopwatch_overflow,
+            resume_running: settings.resume_running,
+
+            laps: laps,
+
+            timer_elapsed: ElapsedTime::new(timer_elapsed),
+            timer_duration: timer_duration,
+            timer_phases: timer_phases,
+            timer_phase: timer_phase,
+            timer_phases_loop: settings.timer_phases_loop,
+
+            default_stopwatch_skin_id: default_skin_ids.stopwatch,
+            default_timer_skin_id: default_skin_ids.timer,
+            default_clock_skin_id: default_skin_ids.clock,
+
+            timer_expired_skin_id:
+                settings.timer_expired_skin_name.as_ref().map(
+                    |name| fnv_1a_64(name.as_bytes())
+                ),
+
+            stopwatch_events: loaded.stopwatch_events.clone(),
+            timer_events: loaded.timer_events.clone(),
+            clock_events: loaded.clock_events.clone(),
+
+            save_data_path: save_data_path,
+            autosave_interval: loaded.autosave_interval,
+            last_save_time: now,
+
+            alarms: alarms,
+            alarm_state: alarm_state,
+            alarm_snooze: loaded.alarm_snooze,
+            alarm_checked_at: None,
+            alarm_sound_request: None,
+            alarm_stop_request: false
+        };
 
-            default_stopwatch_skin_id:
-                fnv_1a_64(settings.default_clock_skin_name.as_bytes()),
-            default_clock_skin_id:
-                fnv_1a_64(settings.default_clock_skin_name.as_bytes()),
+        if resume_running {
+            state.stopwatch_elapsed.start(state.now);
+        }
 
-            stopwatch_events: SettingsLoader::load_stopwatch_events(
-                &settings.stopwatch_events
-            )?,
+        state.update_stopwatch_time();
+        state.update_timer_time();
 
-            clock_events: SettingsLoader::load_clock_events(
-                &settings.clock_events
-            )?,
+        Ok(state)
+    }
 
-            save_data_path: save_data_path
-        })
+    #[inline]
+    fn load_skin_ids(settings: &Settings) -> Vec<u64> {
+        settings.skins.iter().map(
+            |elm| fnv_1a_64(elm.name.as_bytes())
+        ).collect()
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    fn to_alarm_state(
+        saved_alarm_state: SavedAlarmState,
+        now: Instant
+    ) -> AlarmState {
+        match saved_alarm_state {
+            SavedAlarmState::Idle => AlarmState::Idle,
+
+            SavedAlarmState::Ringing(index) => AlarmState::Ringing(index),
+
+            SavedAlarmState::Snoozed {index, until} => AlarmState::Snoozed {
+                index: index,
+                // rings at once if snooze ended while closed.
+                until: now + (until - Utc::now()).to_std().unwrap_or_default()
+            }
+        }
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    fn to_saved_alarm_state(
+        alarm_state: AlarmState,
+        now: Instant
+    ) -> SavedAlarmState {
+        match alarm_state {
+            AlarmState::Idle => SavedAlarmState::Idle,
+
+            AlarmState::Ringing(index) => SavedAlarmState::Ringing(index),
+
+            AlarmState::Snoozed {index, until} => SavedAlarmState::Snoozed {
+                index: index,
+                until: Utc::now() + chrono::Duration::from_std(
+                    until.saturating_duration_since(now)
+                ).unwrap_or_else(|_| chrono::Duration::zero())
+            }
+        }
+    }
+
+    // Replaces the parts of state which come from settings file.
+    fn apply_settings_to_state(state: &mut State, loaded: &LoadedSettings) {
+        let settings = &loaded.settings;
+
+        state.default_stopwatch_skin_id = loaded.default_skin_ids.stopwatch;
+        state.default_timer_skin_id = loaded.default_skin_ids.timer;
+        state.default_clock_skin_id = loaded.default_skin_ids.clock;
+
+        state.timer_expired_skin_id =
+            settings.timer_expired_skin_name.as_ref().map(
+                |name| fnv_1a_64(name.as_bytes())
+            );
+
+        state.stopwatch_events = loaded.stopwatch_events.clone();
+        state.timer_events = loaded.timer_events.clone();
+        state.clock_events = loaded.clock_events.clone();
+
+        state.autosave_interval = loaded.autosave_interval;
+        state.resume_running = settings.resume_running;
+
+        state.clock_format = loaded.clock_format;
+        state.clock_zone = loaded.clock_zone.clone();
+
+        state.stopwatch_limit = loaded.stopwatch_limit.clone();
+        state.stopwatch_overflow = loaded.stopwatch_overflow;
+
+        state.alarms = loaded.alarms.clone();
+        state.alarm_snooze = loaded.alarm_snooze;
+
+        if state.alarm_state.index().map(|index| index >= state.alarms.len())
+            .unwrap_or(false)
+        {
+            state.alarm_state = AlarmState::Idle;
+            state.alarm_stop_request = true;
+        }
+
+        state.skin_ids = Self::load_skin_ids(settings);
+
+        state.skin_overrides.retain(&state.skin_ids);
+
+        if loaded.timer_duration.is_none() {
+            if let WatchMode::Timer(..) = state.watch_mode {
+                state.timer_elapsed.stop(state.now);
+                state.watch_mode = WatchMode::Clock;
+            }
+        }
+
+        state.timer_duration = loaded.timer_duration.clone();
+        state.timer_phases = loaded.timer_phases.clone();
+        state.timer_phases_loop = settings.timer_phases_loop;
+
+        // phases may have been removed from settings file.
+        if state.timer_phase >= state.timer_phases.len() {
+            state.timer_phase = 0;
+        }
+
+        state.set_timer_phase(state.timer_phase);
+
+        state.update_stopwatch_time();
+        state.update_timer_time();
     }
 
     fn update_core(&mut self, ctx: &Context) -> Result<(), Error> {
@@ -291,9 +813,12 @@ impl Application {
             GuiFrame::none().fill(Color32::TRANSPARENT)
         ).show::<Result<(), Error>>(ctx, |ui| {
             self.state.dt = Self::get_dt(ui);
+            self.state.now = Instant::now();
 
             self.model.preproc(&mut self.state)?;
 
+            self.key_bindings.ready(ui, &mut self.state);
+
             {
                 let mut skin = self.current_skin.try_borrow_mut()?;
 
@@ -302,14 +827,38 @@ impl Application {
                 }
             }
 
+            ui.interact(
+                ui.max_rect(),
+                Id::new("context_menu"),
+                Sense::click()
+            ).context_menu(|ui| self.show_context_menu(ui));
+
             self.model.update(&mut self.state)?;
 
+            if self.state.save_request {
+                // keeps running even if autosave fails.
+                if let Err(error) = self.write_save_data() {
+                    Error::from(error).error_log();
+                }
+            }
+
+            if self.state.alarm_stop_request {
+                self.stop_alarm_sound();
+            }
+
+            if let Some(sound) = self.state.alarm_sound_request.take() {
+                // keeps running even if the sound can't be played.
+                if let Err(error) = self.play_alarm_sound(&sound) {
+                    Error::from(error).error_log();
+                }
+            }
+
             if self.state.change_skin_request {
-                self.current_skin = self.skins.get(
+                self.current_skin = Self::find_skin(
+                    &self.skins,
+                    &self.skin_names,
                     self.state.current_skin_id
-                ).ok_or_else(
-                    || Error::NoSkin {id: self.state.current_skin_id}
-                )?.clone();
+                )?;
             }
 
             {
@@ -320,10 +869,151 @@ impl Application {
                 }
             }
 
+            if let Some(reload_error) = self.reload_error.as_ref() {
+                Self::show_reload_error(ui, reload_error);
+            }
+
             Ok(())
         }).inner
     }
 
+    fn show_reload_error(ui: &mut Ui, reload_error: &str) {
+        GuiFrame::popup(ui.style()).show(ui, |ui| {
+            ui.colored_label(
+                Color32::RED,
+                format!("Could not reload settings: {}", reload_error)
+            );
+        });
+    }
+
+    fn show_context_menu(&mut self, ui: &mut Ui) {
+        let state = &mut self.state;
+
+        let mut clicked_btn: Option<Button> = None;
+
+        if ui.button("Switch mode").clicked() {
+            clicked_btn = Some(Button::Switch);
+        }
+
+        ui.menu_button("Skin", |ui| {
+            let is_automatic = state.skin_overrides.get(state.watch_mode)
+                .is_none();
+
+            if ui.radio(is_automatic, "Automatic").clicked() {
+                clicked_btn = Some(Button::ClearSkin);
+            }
+
+            ui.separator();
+
+            for skin_id in state.skin_ids.iter() {
+                let name = match self.skin_names.get(*skin_id) {
+                    Some(name) => name.as_str(),
+                    None => continue
+                };
+
+                if ui.radio(state.current_skin_id == *skin_id, name).clicked() {
+                    clicked_btn = Some(Button::SelectSkin(*skin_id));
+                }
+            }
+        });
+
+        let mut always_on_top = state.always_on_top;
+
+        if ui.checkbox(&mut always_on_top, "Always on top").clicked() {
+            clicked_btn = Some(Button::ToggleAlwaysOnTop);
+        }
+
+        ui.separator();
+
+        if ui.add_enabled(
+            state.watch_mode != WatchMode::Clock,
+            GuiButton::new("Reset")
+        ).clicked() {
+            clicked_btn = Some(Button::Reset);
+        }
+
+        if ui.add_enabled(
+            state.alarm_state != AlarmState::Idle,
+            GuiButton::new("Dismiss alarm")
+        ).clicked() {
+            clicked_btn = Some(Button::DismissAlarm);
+        }
+
+        if ui.add_enabled(
+            matches!(state.alarm_state, AlarmState::Ringing(..)),
+            GuiButton::new("Snooze alarm")
+        ).clicked() {
+            clicked_btn = Some(Button::SnoozeAlarm);
+        }
+
+        ui.separator();
+
+        if ui.button("Reload settings").clicked() {
+            state.reload_request = true;
+            ui.close_menu();
+        }
+
+        ui.separator();
+
+        if ui.button("Quit").clicked() {
+            clicked_btn = Some(Button::Quit);
+        }
+
+        if clicked_btn.is_some() {
+            state.clicked_btn = clicked_btn;
+            ui.close_menu();
+        }
+    }
+
+    fn write_save_data(&mut self) -> io::Result<()> {
+        let mut save_data = SaveData::default();
+
+        save_data.watch_mode = self.state.watch_mode;
+        save_data.stopwatch_elapsed =
+            self.state.stopwatch_elapsed.elapsed(self.state.now);
+        save_data.stopwatch_running = (
+            self.state.resume_running
+                && self.state.watch_mode
+                    == WatchMode::Stopwatch(StopwatchMode::Running)
+        ).then(Utc::now);
+        save_data.laps = self.state.laps.clone();
+        save_data.timer_remaining = self.state.timer_is_enabled().then(
+            || self.state.timer_remaining()
+        );
+        save_data.timer_phase = self.state.timer_phase;
+        save_data.skin_overrides = self.state.skin_overrides;
+        save_data.alarm_state =
+            Self::to_saved_alarm_state(self.state.alarm_state, self.state.now);
+
+        save_data.write_file(
+            &self.state.save_data_path,
+            &mut self.save_data_buf
+        )
+    }
+
+    // Runs `alarm_sound_command` with the sound file as the last argument.
+    fn play_alarm_sound(&mut self, sound: &Path) -> io::Result<()> {
+        self.stop_alarm_sound();
+
+        let (program, args) = match self.alarm_sound_command.split_first() {
+            Some(command) => command,
+            None => {return Ok(());}
+        };
+
+        self.alarm_sound =
+            Some(Command::new(program).args(args).arg(sound).spawn()?);
+
+        Ok(())
+    }
+
+    fn stop_alarm_sound(&mut self) {
+        if let Some(mut alarm_sound) = self.alarm_sound.take() {
+            // the command may have already finished.
+            let _ = alarm_sound.kill();
+            let _ = alarm_sound.wait();
+        }
+    }
+
     #[inline]
     fn get_dt(ui: &Ui) -> f32 {
         ui.input(|i_state| i_state.stable_dt)
@@ -335,7 +1025,7 @@ impl App for Application {
         ctx.request_repaint();
 
         ctx.set_pixels_per_point(1.0);
-        frame.set_always_on_top(true);
+        frame.set_always_on_top(self.state.always_on_top);
 
         frame.set_window_size(self.window_size);
 
@@ -348,6 +1038,29 @@ impl App for Application {
             frame.close();
         }
 
+        let files_are_modified = match self.settings_watcher.as_mut() {
+            Some(settings_watcher) => settings_watcher.check(self.state.now),
+            None => false
+        };
+
+        if self.state.reload_request || files_are_modified {
+            // keeps current skins and settings if reloading fails.
+            match self.reload_settings(ctx) {
+                Ok(()) => {
+                    self.reload_error = None;
+                },
+
+                Err(error) => {
+                    error.error_log();
+                    self.reload_error = Some(error.to_string());
+                }
+            }
+        }
+
+        if self.state.minimize_request {
+            frame.set_minimized(true);
+        }
+
         if self.state.quit_request {
             *self.exit_code.borrow_mut() = ExitCode::SUCCESS;
             frame.close();
@@ -360,26 +1073,13 @@ impl App for Application {
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
-        let mut buf = Vec::<u8>::new();
-
-        let mut save_data = SaveData::from_variables(
-            self.state.watch_mode,
-            self.state.current_stopwatch_time.clone(),
-            self.state.saved_time
-        );
+        self.state.now = Instant::now();
 
-        save_data.write_bytes(&mut buf);
-
-        let mut file = BufWriter::new(match OpenOptions::new()
-            .write(true)
-            .create(true)
-            .open(&self.state.save_data_path)
-        {
-            Ok(file) => file,
-            Err(..) => {return;}
-        });
+        if let Err(error) = self.write_save_data() {
+            Error::from(error).error_log();
+        }
 
-        let _ = file.write_all(&buf).and_then(|_| file.flush());
+        self.stop_alarm_sound();
     }
 }
 
@@ -391,3 +1091,380 @@ impl App for DummyApplication {
         frame.close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::TimeElement;
+
+    const EXAMPLE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/example_settings.yaml"
+    );
+
+    #[allow(clippy::redundant_field_names)]
+    fn gen_time(hours: u32, minutes: u32, seconds: u32) -> WatchTime {
+        WatchTime {
+            hours: hours,
+            minutes: minutes,
+            seconds: seconds,
+            cents: 0
+        }
+    }
+
+    #[test]
+    fn find_skin_test() {
+        let loaded = Application::load_settings_from(
+            &Context::default(),
+            Application::read_settings_file(EXAMPLE).unwrap(),
+            Path::new(EXAMPLE).parent().unwrap(),
+            &mut LoadProblems::new()
+        ).unwrap();
+
+        assert!(Application::find_skin(
+            &loaded.skins,
+            &loaded.skin_names,
+            fnv_1a_64(b"skin_red")
+        ).is_ok());
+
+        let no_skin = |skin_id: u64| match Application::find_skin(
+            &ChobitMap::new(1),
+            &loaded.skin_names,
+            skin_id
+        ) {
+            Err(Error::NoSkin {name}) => name,
+            _ => panic!("expected NoSkin")
+        };
+
+        // the name is taken from settings file if it is known.
+        assert_eq!(no_skin(fnv_1a_64(b"skin_red")), "skin_red");
+        assert_eq!(no_skin(123), "(ID: 123)");
+
+        assert_eq!(
+            Error::NoSkin {name: "skin_red".to_string()}.to_string(),
+            r#"{"error": NoSkin, "name": "skin_red"}"#
+        );
+    }
+
+    #[test]
+    fn example_settings_skin_test() {
+        // no save data in this directory.
+        let root_dir = std::env::temp_dir().join(format!(
+            "anipwatch_example_settings_skin_test_{}",
+            std::process::id()
+        ));
+
+        let loaded = Application::load_settings_from(
+            &Context::default(),
+            Application::read_settings_file(EXAMPLE).unwrap(),
+            Path::new(EXAMPLE).parent().unwrap(),
+            &mut LoadProblems::new()
+        ).unwrap();
+
+        let mut state = Application::settings_to_state(
+            &loaded,
+            &root_dir
+        ).unwrap();
+
+        state.init();
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+
+        let skin_white = fnv_1a_64(b"skin_white");
+        let skin_red = fnv_1a_64(b"skin_red");
+        let skin_blue = fnv_1a_64(b"skin_blue");
+
+        assert_eq!(state.watch_mode, WatchMode::Clock);
+
+        // clock mode reads the real time, so events are checked directly.
+        state.current_clock_time = gen_time(7, 0, 0);
+        assert_eq!(state.find_skin_id_from_clock_events(), skin_white);
+
+        state.current_clock_time = gen_time(8, 0, 0);
+        assert_eq!(state.find_skin_id_from_clock_events(), skin_red);
+
+        state.current_clock_time = gen_time(16, 1, 0);
+        assert_eq!(state.find_skin_id_from_clock_events(), skin_blue);
+
+        // the example has no timer, so it switches to stopwatch mode.
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::Switch);
+        assert!(model.update(&mut state).is_ok());
+
+        assert_eq!(
+            state.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Stopped)
+        );
+        assert_eq!(state.current_skin_id, skin_white);
+
+        let stopwatch_skins = [
+            (gen_time(0, 0, 29), skin_white),
+            (gen_time(0, 0, 30), skin_red),
+            (gen_time(0, 1, 0), skin_blue),
+            (gen_time(0, 1, 30), skin_white)
+        ];
+
+        for (time, skin_id) in stopwatch_skins {
+            state.stopwatch_elapsed = ElapsedTime::new(time.to_duration());
+            state.update_stopwatch_time();
+
+            assert!(model.preproc(&mut state).is_ok());
+            assert!(model.update(&mut state).is_ok());
+
+            assert_eq!(state.current_skin_id, skin_id);
+        }
+    }
+
+    #[test]
+    fn default_skin_ids_test() {
+        let root_dir = std::env::temp_dir().join(format!(
+            "anipwatch_default_skin_ids_test_{}",
+            std::process::id()
+        ));
+
+        let mut settings = Application::read_settings_file(EXAMPLE).unwrap();
+
+        // every mode shows its own default skin.
+        settings.default_stopwatch_skin_name = "skin_red".to_string();
+        settings.default_timer_skin_name = Some("skin_blue".to_string());
+        settings.default_clock_skin_name = "skin_white".to_string();
+        settings.stopwatch_events.clear();
+        settings.clock_events.clear();
+
+        settings.timer_duration = Some(TimeElement {
+            centiseconds: 0,
+            seconds: 0,
+            minutes: 3,
+            hours: 0
+        });
+
+        let loaded = Application::load_settings_from(
+            &Context::default(),
+            settings,
+            Path::new(EXAMPLE).parent().unwrap(),
+            &mut LoadProblems::new()
+        ).unwrap();
+
+        let mut state = Application::settings_to_state(
+            &loaded,
+            &root_dir
+        ).unwrap();
+
+        state.init();
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+
+        assert_eq!(state.watch_mode, WatchMode::Clock);
+        assert_eq!(state.current_skin_id, fnv_1a_64(b"skin_white"));
+
+        let modes = [
+            (
+                WatchMode::Stopwatch(StopwatchMode::Stopped),
+                fnv_1a_64(b"skin_red")
+            ),
+            (
+                WatchMode::Timer(TimerMode::Stopped),
+                fnv_1a_64(b"skin_blue")
+            ),
+            (WatchMode::Clock, fnv_1a_64(b"skin_white"))
+        ];
+
+        for (watch_mode, skin_id) in modes {
+            assert!(model.preproc(&mut state).is_ok());
+            state.clicked_btn = Some(Button::Switch);
+            assert!(model.update(&mut state).is_ok());
+
+            assert_eq!(state.watch_mode, watch_mode);
+            assert_eq!(state.current_skin_id, skin_id);
+        }
+    }
+
+    #[test]
+    fn reload_keeps_state_test() {
+        let root_dir = std::env::temp_dir().join(format!(
+            "anipwatch_reload_keeps_state_test_{}",
+            std::process::id()
+        ));
+
+        let loaded = Application::load_settings_from(
+            &Context::default(),
+            Application::read_settings_file(EXAMPLE).unwrap(),
+            Path::new(EXAMPLE).parent().unwrap(),
+            &mut LoadProblems::new()
+        ).unwrap();
+
+        let mut state = Application::settings_to_state(
+            &loaded,
+            &root_dir
+        ).unwrap();
+
+        state.init();
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+
+        let skin_red = fnv_1a_64(b"skin_red");
+
+        let lap = LapTime {
+            split: gen_time(0, 0, 10),
+            lap: gen_time(0, 0, 10)
+        };
+
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Stopped);
+        state.stopwatch_elapsed =
+            ElapsedTime::new(gen_time(0, 0, 42).to_duration());
+        state.laps = vec![lap.clone()];
+        state.skin_overrides.stopwatch = Some(skin_red);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.reload_request = true;
+
+        // same steps as reload_settings() without a window.
+        Application::apply_settings_to_state(&mut state, &loaded);
+        state.init();
+        assert!(model.init(&mut state).is_ok());
+
+        assert!(!state.reload_request);
+        assert_eq!(
+            state.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Stopped)
+        );
+        assert_eq!(state.current_stopwatch_time, gen_time(0, 0, 42));
+        assert_eq!(state.laps, vec![lap]);
+        assert_eq!(state.skin_overrides.stopwatch, Some(skin_red));
+        assert_eq!(state.current_skin_id, skin_red);
+    }
+
+    #[test]
+    #[allow(clippy::redundant_field_names)]
+    fn reload_stops_removed_alarm_test() {
+        let ctx = Context::default();
+
+        let loaded = Application::load_settings_from(
+            &ctx,
+            Application::read_settings_file(EXAMPLE).unwrap(),
+            Path::new(EXAMPLE).parent().unwrap(),
+            &mut LoadProblems::new()
+        ).unwrap();
+
+        let mut state = Application::settings_to_state(
+            &loaded,
+            Path::new(EXAMPLE).parent().unwrap()
+        ).unwrap();
+
+        state.init();
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+
+        let current_skin = Application::find_skin(
+            &loaded.skins,
+            &loaded.skin_names,
+            state.current_skin_id
+        ).unwrap();
+
+        // the example settings file has no alarms.
+        state.alarm_state = AlarmState::Ringing(0);
+
+        let alarm_sound = Command::new("sleep").arg("60").spawn().unwrap();
+
+        let mut application = Application {
+            state: state,
+            window_size: loaded.window_size,
+            _textures: loaded.textures,
+            skins: loaded.skins,
+            skin_names: loaded.skin_names,
+            current_skin: current_skin,
+            key_bindings: loaded.key_bindings,
+            model: model,
+            save_data_buf: Vec::<u8>::new(),
+            settings_file_name: EXAMPLE.to_string(),
+            settings_watcher: None,
+            reload_error: None,
+            alarm_sound_command: loaded.alarm_sound_command,
+            alarm_sound: Some(alarm_sound),
+
+            exit_code: Rc::new(RefCell::new(ExitCode::SUCCESS))
+        };
+
+        assert!(application.reload_settings(&ctx).is_ok());
+
+        assert_eq!(application.state.alarm_state, AlarmState::Idle);
+        assert!(application.alarm_sound.is_none());
+    }
+
+    #[test]
+    fn resume_running_test() {
+        let root_dir = std::env::temp_dir().join(format!(
+            "anipwatch_resume_running_test_{}",
+            std::process::id()
+        ));
+
+        std::fs::create_dir_all(&root_dir).unwrap();
+
+        let mut loaded = Application::load_settings_from(
+            &Context::default(),
+            Application::read_settings_file(EXAMPLE).unwrap(),
+            Path::new(EXAMPLE).parent().unwrap(),
+            &mut LoadProblems::new()
+        ).unwrap();
+
+        let elapsed = Duration::from_secs(10);
+        let closed_time = chrono::Duration::hours(1);
+
+        // saved an hour ago while running.
+        let mut save_data = SaveData::default();
+
+        save_data.watch_mode = WatchMode::Stopwatch(StopwatchMode::Running);
+        save_data.stopwatch_elapsed = elapsed;
+        save_data.stopwatch_running = Some(Utc::now() - closed_time);
+
+        save_data.write_file(
+            &root_dir.join(&loaded.settings.save_data_file),
+            &mut Vec::new()
+        ).unwrap();
+
+        loaded.settings.resume_running = true;
+
+        let state = Application::settings_to_state(
+            &loaded,
+            &root_dir
+        ).unwrap();
+
+        assert_eq!(
+            state.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Running)
+        );
+        let resumed = state.stopwatch_elapsed.elapsed(state.now);
+
+        // keeps counting.
+        assert_eq!(
+            state.stopwatch_elapsed.elapsed(state.now + Duration::from_secs(1)),
+            resumed + Duration::from_secs(1)
+        );
+
+        let expected = elapsed + closed_time.to_std().unwrap();
+        assert!(resumed >= expected);
+        assert!(resumed < expected + Duration::from_secs(60));
+
+        loaded.settings.resume_running = false;
+
+        let state = Application::settings_to_state(
+            &loaded,
+            &root_dir
+        ).unwrap();
+
+        assert_eq!(
+            state.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Stopped)
+        );
+        assert_eq!(
+            state.stopwatch_elapsed.elapsed(state.now + Duration::from_secs(1)),
+            elapsed
+        );
+
+        std::fs::remove_dir_all(&root_dir).unwrap();
+    }
+}
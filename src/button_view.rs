@@ -1,4 +1,8 @@
-use crate::{error::Error, state::{State, Button}, view::View};
+use crate::{
+    error::Error,
+    state::{State, Button, WatchMode, StopwatchMode},
+    view::View
+};
 
 use eframe::{
     egui::{
@@ -12,6 +16,44 @@ use eframe::{
 
 use chobitlibs::chobit_ani_value::ChobitAniValue;
 
+/// Visual state of a button.
+/// Each state is a row of the texture. (from top to bottom)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ButtonState {
+    Idle = 0,
+    Hover = 1,
+    Pressed = 2,
+    Disabled = 3
+}
+
+impl ButtonState {
+    pub fn from_pointer(ui: &Ui, region: &Rect) -> Self {
+        ui.input(|i_state| {
+            let pointer = &i_state.pointer;
+
+            match pointer.hover_pos() {
+                Some(pos) if region.contains(pos) => {
+                    if pointer.primary_down() {
+                        Self::Pressed
+                    } else {
+                        Self::Hover
+                    }
+                },
+
+                _ => Self::Idle
+            }
+        })
+    }
+
+    /// Falls back to the idle row if the texture doesn't have this state.
+    #[inline]
+    pub fn row(self, states: usize) -> usize {
+        let row = self as usize;
+
+        if row < states {row} else {Self::Idle as usize}
+    }
+}
+
 pub struct ButtonView {
     depth: i32,
 
@@ -19,15 +61,19 @@ pub struct ButtonView {
 
     region: Rect,
     ani_value: ChobitAniValue,
+    states: usize,
+    button_state: ButtonState,
 
     button: Button
 }
 
 impl ButtonView {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(
         depth: i32,
         texture_id: TextureId,
         region: Rect,
+        states: usize,
         button: Button
     ) -> Self {
         Self {
@@ -36,13 +82,42 @@ impl ButtonView {
             texture_id: texture_id,
 
             region: region,
-            ani_value: ChobitAniValue::new(1, &[1], 1.0).expect(
+            ani_value: ChobitAniValue::new(1, &vec![1; states], 1.0).expect(
                 "Error at ButtonView::new()"
             ),
+            states: states,
+            button_state: ButtonState::Idle,
 
             button: button
         }
     }
+
+    fn is_enabled(&self, state: &State) -> bool {
+        match (self.button, state.watch_mode) {
+            (Button::StartStop, WatchMode::Clock) => false,
+            (Button::Reset, WatchMode::Clock) => false,
+
+            (
+                Button::Lap,
+                WatchMode::Stopwatch(StopwatchMode::Running)
+            ) => true,
+
+            (Button::Lap, _) => false,
+
+            _ => true
+        }
+    }
+
+    // disabled buttons ignore the pointer.
+    fn current_row(&self, state: &State) -> usize {
+        let button_state = if self.is_enabled(state) {
+            self.button_state
+        } else {
+            ButtonState::Disabled
+        };
+
+        button_state.row(self.states)
+    }
 }
 
 impl View for ButtonView {
@@ -55,19 +130,22 @@ impl View for ButtonView {
 
             if pointer.has_pointer() {
                 if let Some(pos) = pointer.interact_pos() {
-                    if self.region.contains(pos) {
-                        if pointer.primary_clicked() {
-                            state.clicked_btn = Some(self.button);
-                        }
+                    if self.region.contains(pos)
+                        && pointer.primary_clicked()
+                    {
+                        state.clicked_btn = Some(self.button);
                     }
                 }
             }
         });
 
+        self.button_state = ButtonState::from_pointer(ui, &self.region);
+
         Ok(())
     }
 
-    fn show(&mut self, ui: &Ui, _state: &mut State) -> Result<(), Error> {
+    fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
+        self.ani_value.set_row(self.current_row(state));
         self.ani_value.set_frame(0);
         let (left, top, right, bottom) =
             self.ani_value.uv_frame_left_top_right_bottom();
@@ -90,3 +168,53 @@ impl View for ButtonView {
         Ok(())
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use super::*;
+
+    fn gen_button_view(states: usize, button: Button) -> ButtonView {
+        ButtonView::new(0, TextureId::default(), Rect::NOTHING, states, button)
+    }
+
+    #[test]
+    fn current_row_test() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Stopped);
+
+        let mut view = gen_button_view(4, Button::StartStop);
+
+        let rows = [
+            (ButtonState::Idle, 0),
+            (ButtonState::Hover, 1),
+            (ButtonState::Pressed, 2)
+        ];
+
+        for (button_state, row) in rows {
+            view.button_state = button_state;
+            assert_eq!(view.current_row(&state), row);
+        }
+
+        // start/stop button is disabled in clock mode.
+        state.watch_mode = WatchMode::Clock;
+        view.button_state = ButtonState::Pressed;
+        assert_eq!(view.current_row(&state), 3);
+
+        // without a disabled row, the idle row is used.
+        let mut view = gen_button_view(3, Button::StartStop);
+        view.button_state = ButtonState::Hover;
+        assert_eq!(view.current_row(&state), 0);
+
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Stopped);
+        assert_eq!(view.current_row(&state), 1);
+
+        // a single row is used for every state.
+        let mut view = gen_button_view(1, Button::Lap);
+        view.button_state = ButtonState::Pressed;
+        assert_eq!(view.current_row(&state), 0);
+
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Running);
+        assert_eq!(view.current_row(&state), 0);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{error::Error, state::{State, WatchMode}, view::View};
+use crate::{
+    error::Error,
+    state::{State, WatchMode, WatchTime},
+    view::View
+};
 
 use eframe::{
     egui::{
@@ -12,65 +16,240 @@ use eframe::{
 
 use chobitlibs::chobit_ani_value::ChobitAniValue;
 
+use chrono::{NaiveDate, Datelike};
+
+/// Fields of time shown by `DisplayView`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeField {
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Cents
+}
+
+/// Value of a field of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldValue {
+    pub value: u32,
+
+    // all the higher fields are zero.
+    pub higher_is_zero: bool
+}
+
+impl FieldValue {
+    /// The field and all the higher fields are zero.
+    #[inline]
+    pub fn is_leading_zero(&self) -> bool {
+        self.higher_is_zero && (self.value == 0)
+    }
+}
+
+/// Values of all fields of a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeFields {
+    pub days: FieldValue,
+    pub hours: FieldValue,
+    pub minutes: FieldValue,
+    pub seconds: FieldValue,
+    pub cents: FieldValue
+}
+
+impl TimeFields {
+    /// If `has_days` is true, `hours` are hours of the day. (0 to 23)
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(time: &WatchTime, has_days: bool) -> Self {
+        let (days, hours) = if has_days {
+            (time.hours / 24, time.hours % 24)
+        } else {
+            (0, time.hours)
+        };
+
+        let days = FieldValue {value: days, higher_is_zero: true};
+
+        let hours = FieldValue {
+            value: hours,
+            higher_is_zero: days.is_leading_zero()
+        };
+
+        let minutes = FieldValue {
+            value: time.minutes,
+            higher_is_zero: hours.is_leading_zero()
+        };
+
+        let seconds = FieldValue {
+            value: time.seconds,
+            higher_is_zero: minutes.is_leading_zero()
+        };
+
+        let cents = FieldValue {
+            value: time.cents,
+            higher_is_zero: seconds.is_leading_zero()
+        };
+
+        Self {
+            days: days,
+            hours: hours,
+            minutes: minutes,
+            seconds: seconds,
+            cents: cents
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, field: TimeField) -> FieldValue {
+        match field {
+            TimeField::Days => self.days,
+            TimeField::Hours => self.hours,
+            TimeField::Minutes => self.minutes,
+            TimeField::Seconds => self.seconds,
+            TimeField::Cents => self.cents
+        }
+    }
+}
+
+/// Shows the lower digits of a number. The 1st digit is the ones place.
 pub struct DisplayNumber {
-    region_d1: Rect,
-    region_d2: Rect,
-    ani_value_d1: ChobitAniValue,
-    ani_value_d2: ChobitAniValue,
+    regions: Vec<Rect>,
+    ani_values: Vec<ChobitAniValue>,
+
+    hide_leading_zeros: bool,
+    hide_when_zero: bool
 }
 
 impl DisplayNumber {
     #[inline]
+    #[allow(clippy::redundant_field_names)]
     pub fn new(
-        region_d1: Rect,
-        region_d2: Rect,
+        regions: Vec<Rect>,
+        hide_leading_zeros: bool,
+        hide_when_zero: bool
     ) -> Self {
         let ani_value = ChobitAniValue::new(10, &[10], 1.0).expect(
             "Error at DisplayNumber::new()"
         );
 
         Self {
-            region_d1: region_d1,
-            region_d2: region_d2,
-            ani_value_d1: ani_value.clone(),
-            ani_value_d2: ani_value
+            ani_values: vec![ani_value; regions.len()],
+            regions: regions,
+
+            hide_leading_zeros: hide_leading_zeros,
+            hide_when_zero: hide_when_zero
         }
     }
 
-    fn show(
+    #[inline]
+    pub fn show(
         &mut self,
         ui: &Ui,
         texture_id: TextureId,
         number: u32
     ) -> Result<(), Error> {
-        let digit_1 = (number % 10) as usize;
-        let digit_2 = ((number / 10) % 10) as usize;
+        self.show_field(
+            ui,
+            texture_id,
+            FieldValue {value: number, higher_is_zero: true}
+        )
+    }
 
-        self.ani_value_d1.set_frame(digit_1);
-        self.ani_value_d2.set_frame(digit_2);
+    /// Number of digits to show from the ones place.
+    ///
+    /// Zeros are hidden only while the higher fields are zero,
+    /// e.g. hours are zero for minutes.
+    pub fn visible_digits(&self, field: FieldValue) -> usize {
+        if self.hide_when_zero && field.is_leading_zero() {
+            return 0;
+        }
 
-        let (left_1, top_1, right_1, bottom_1) =
-            self.ani_value_d1.uv_frame_left_top_right_bottom();
+        if !(self.hide_leading_zeros && field.higher_is_zero) {
+            return self.regions.len();
+        }
 
-        let (left_2, top_2, right_2, bottom_2) =
-            self.ani_value_d2.uv_frame_left_top_right_bottom();
+        let mut digits = 1;
+        let mut rest = field.value / 10;
 
-        ui.painter().image(
-            texture_id,
-            self.region_d1,
-            Rect::from_min_max(
-                Pos2::new(*left_1, *top_1),
-                Pos2::new(*right_1, *bottom_1)
-            ),
-            Color32::WHITE
-        );
+        while rest > 0 {
+            digits += 1;
+            rest /= 10;
+        }
+
+        digits.min(self.regions.len())
+    }
+
+    /// Shows a field of time.
+    pub fn show_field(
+        &mut self,
+        ui: &Ui,
+        texture_id: TextureId,
+        field: FieldValue
+    ) -> Result<(), Error> {
+        let digits = self.visible_digits(field);
+
+        let mut number = field.value;
+
+        for (region, ani_value) in self.regions.iter()
+            .zip(self.ani_values.iter_mut())
+            .take(digits)
+        {
+            ani_value.set_frame((number % 10) as usize);
+            number /= 10;
+
+            let (left, top, right, bottom) =
+                ani_value.uv_frame_left_top_right_bottom();
+
+            ui.painter().image(
+                texture_id,
+                *region,
+                Rect::from_min_max(
+                    Pos2::new(*left, *top),
+                    Pos2::new(*right, *bottom)
+                ),
+                Color32::WHITE
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Shows a weekday from 7 frames. (Monday to Sunday)
+pub struct DisplayWeekday {
+    texture_id: TextureId,
+    region: Rect,
+    ani_value: ChobitAniValue
+}
+
+impl DisplayWeekday {
+    #[inline]
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(texture_id: TextureId, region: Rect) -> Self {
+        Self {
+            texture_id: texture_id,
+            region: region,
+            ani_value: ChobitAniValue::new(7, &[7], 1.0).expect(
+                "Error at DisplayWeekday::new()"
+            )
+        }
+    }
+
+    /// Frame of the weekday of `date`. Monday is 0 and Sunday is 6.
+    #[inline]
+    pub fn frame(date: &NaiveDate) -> usize {
+        date.weekday().num_days_from_monday() as usize
+    }
+
+    pub fn show(&mut self, ui: &Ui, date: &NaiveDate) -> Result<(), Error> {
+        self.ani_value.set_frame(Self::frame(date));
+
+        let (left, top, right, bottom) =
+            self.ani_value.uv_frame_left_top_right_bottom();
 
         ui.painter().image(
-            texture_id,
-            self.region_d2,
+            self.texture_id,
+            self.region,
             Rect::from_min_max(
-                Pos2::new(*left_2, *top_2),
-                Pos2::new(*right_2, *bottom_2)
+                Pos2::new(*left, *top),
+                Pos2::new(*right, *bottom)
             ),
             Color32::WHITE
         );
@@ -79,36 +258,109 @@ impl DisplayNumber {
     }
 }
 
+/// Time fields of `DisplayView`.
+///
+/// If `days` is specified, `hours` shows hours of the day. (0 to 23)
+#[derive(Default)]
+pub struct DisplayTime {
+    pub cents: Option<DisplayNumber>,
+    pub seconds: Option<DisplayNumber>,
+    pub minutes: Option<DisplayNumber>,
+    pub hours: Option<DisplayNumber>,
+    pub days: Option<DisplayNumber>
+}
+
+/// Date fields of `DisplayView`. They are shown in clock mode.
+#[derive(Default)]
+pub struct DisplayDate {
+    pub year: Option<DisplayNumber>,
+    pub month: Option<DisplayNumber>,
+    pub day: Option<DisplayNumber>,
+    pub weekday: Option<DisplayWeekday>
+}
+
 pub struct DisplayView {
     depth: i32,
 
     texture_id: TextureId,
 
-    cents: Option<DisplayNumber>,
-    seconds: Option<DisplayNumber>,
-    minutes: Option<DisplayNumber>,
-    hours: Option<DisplayNumber>
+    time: DisplayTime,
+    date: DisplayDate
 }
 
 impl DisplayView {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(
         depth: i32,
         texture_id: TextureId,
-        cents: Option<DisplayNumber>,
-        seconds: Option<DisplayNumber>,
-        minutes: Option<DisplayNumber>,
-        hours: Option<DisplayNumber>
+        time: DisplayTime,
+        date: DisplayDate
     ) -> Self {
         Self {
             depth: depth,
 
             texture_id: texture_id,
 
-            cents: cents,
-            seconds: seconds,
-            minutes: minutes,
-            hours: hours
+            time: time,
+            date: date
+        }
+    }
+
+    /// Time shown in the current watch mode.
+    pub fn current_time(state: &State) -> WatchTime {
+        match state.watch_mode {
+            WatchMode::Stopwatch(..) => state.current_stopwatch_time.clone(),
+            WatchMode::Timer(..) => state.current_timer_time.clone(),
+            WatchMode::Clock => state.clock_display_time()
+        }
+    }
+
+    pub fn show_time(
+        &mut self,
+        ui: &Ui,
+        time: &WatchTime
+    ) -> Result<(), Error> {
+        let fields = TimeFields::new(time, self.time.days.is_some());
+
+        let displays = [
+            (&mut self.time.days, fields.days),
+            (&mut self.time.hours, fields.hours),
+            (&mut self.time.minutes, fields.minutes),
+            (&mut self.time.seconds, fields.seconds),
+            (&mut self.time.cents, fields.cents)
+        ];
+
+        for (display, field) in displays {
+            if let Some(display) = display.as_mut() {
+                display.show_field(ui, self.texture_id, field)?;
+            }
         }
+
+        Ok(())
+    }
+
+    pub fn show_date(
+        &mut self,
+        ui: &Ui,
+        date: &NaiveDate
+    ) -> Result<(), Error> {
+        if let Some(year) = self.date.year.as_mut() {
+            year.show(ui, self.texture_id, date.year().unsigned_abs())?;
+        }
+
+        if let Some(month) = self.date.month.as_mut() {
+            month.show(ui, self.texture_id, date.month())?;
+        }
+
+        if let Some(day) = self.date.day.as_mut() {
+            day.show(ui, self.texture_id, date.day())?;
+        }
+
+        if let Some(weekday) = self.date.weekday.as_mut() {
+            weekday.show(ui, date)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -122,32 +374,122 @@ impl View for DisplayView {
     }
 
     fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
-        let current_time = match state.watch_mode {
-            WatchMode::Stopwatch(..) => state.current_stopwatch_time.clone(),
-            WatchMode::Clock => state.current_clock_time.clone()
-        };
-
-        if let Some(cents) = self.cents.as_mut() {
-            cents.show(ui, self.texture_id, current_time.cents)?;
+        if state.watch_mode == WatchMode::Clock {
+            self.show_date(ui, &state.current_date)?;
         }
 
-        if let Some(seconds) = self.seconds.as_mut() {
-            seconds.show(ui, self.texture_id, current_time.seconds)?;
-        }
+        self.show_time(ui, &Self::current_time(state))
+    }
 
-        if let Some(minutes) = self.minutes.as_mut() {
-            minutes.show(ui, self.texture_id, current_time.minutes)?;
+    fn init(&mut self, _state: &mut State) -> Result<(), Error> {
+        // pass
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gen_number(
+        digits: usize,
+        hide_leading_zeros: bool,
+        hide_when_zero: bool
+    ) -> DisplayNumber {
+        DisplayNumber::new(
+            vec![Rect::NOTHING; digits],
+            hide_leading_zeros,
+            hide_when_zero
+        )
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    fn gen_time(
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+        cents: u32
+    ) -> WatchTime {
+        WatchTime {
+            hours: hours,
+            minutes: minutes,
+            seconds: seconds,
+            cents: cents
         }
+    }
+
+    // digits of hours, minutes, seconds and centiseconds.
+    fn visible_digits(time: &WatchTime) -> [usize; 4] {
+        let hours = gen_number(2, true, true);
+        let minutes = gen_number(2, true, false);
+        let seconds = gen_number(2, false, false);
+        let cents = gen_number(2, false, false);
+
+        let fields = TimeFields::new(time, false);
 
-        if let Some(hours) = self.hours.as_mut() {
-            hours.show(ui, self.texture_id, current_time.hours)?;
+        [
+            hours.visible_digits(fields.hours),
+            minutes.visible_digits(fields.minutes),
+            seconds.visible_digits(fields.seconds),
+            cents.visible_digits(fields.cents)
+        ]
+    }
+
+    #[test]
+    fn visible_digits_test() {
+        // "5:03.21"
+        assert_eq!(visible_digits(&gen_time(0, 5, 3, 21)), [0, 1, 2, 2]);
+
+        // "0:00.00"
+        assert_eq!(visible_digits(&gen_time(0, 0, 0, 0)), [0, 1, 2, 2]);
+
+        // "59:59.99"
+        assert_eq!(visible_digits(&gen_time(0, 59, 59, 99)), [0, 2, 2, 2]);
+
+        // hours are hidden until 1 hour. "1:00:00.00"
+        assert_eq!(visible_digits(&gen_time(1, 0, 0, 0)), [1, 2, 2, 2]);
+
+        // "12:00:00.00"
+        assert_eq!(visible_digits(&gen_time(12, 0, 0, 0)), [2, 2, 2, 2]);
+
+        // more digits than regions.
+        let number = gen_number(2, true, false);
+        assert_eq!(
+            number.visible_digits(FieldValue {
+                value: 123,
+                higher_is_zero: true
+            }),
+            2
+        );
+    }
+
+    #[test]
+    fn weekday_frame_test() {
+        // 2024-01-01 is Monday.
+        for day in 1..=7 {
+            let date = NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+
+            assert_eq!(DisplayWeekday::frame(&date), (day - 1) as usize);
         }
 
-        Ok(())
+        let date = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
+        assert_eq!(DisplayWeekday::frame(&date), 6);
     }
 
-    fn init(&mut self, _state: &mut State) -> Result<(), Error> {
-        // pass
-        Ok(())
+    #[test]
+    fn time_fields_test() {
+        let fields = TimeFields::new(&gen_time(49, 0, 0, 0), true);
+
+        assert_eq!(fields.days, FieldValue {value: 2, higher_is_zero: true});
+        assert_eq!(fields.hours, FieldValue {value: 1, higher_is_zero: false});
+        assert!(!fields.minutes.higher_is_zero);
+
+        let fields = TimeFields::new(&gen_time(0, 0, 7, 0), true);
+
+        assert!(fields.get(TimeField::Days).is_leading_zero());
+        assert!(fields.get(TimeField::Hours).is_leading_zero());
+        assert!(fields.get(TimeField::Minutes).is_leading_zero());
+        assert!(!fields.get(TimeField::Seconds).is_leading_zero());
+        assert!(!fields.get(TimeField::Cents).is_leading_zero());
     }
 }
@@ -1,6 +1,7 @@
 use log::error;
 
 use std::{
+    fmt,
     io::Error as IOError,
     cell::BorrowMutError
 };
@@ -26,7 +27,7 @@ pub enum Error {
     ChobitMapError(ChobitMapError),
     ChobitAniValueError(ChobitAniValueError),
     YamlError(YamlError),
-    NoSkin {id: u64},
+    NoSkin {name: String},
     LoadError(LoadError),
     ApplicationError(ApplicationError),
     BorrowMutError(BorrowMutError)
@@ -88,56 +89,50 @@ impl From<std::cell::BorrowMutError> for Error {
     }
 }
 
-impl Error {
-    pub fn error_log(&self) {
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Error::IOError(error) => {
-                error!(
-                    r#"{{"error": IOError, "message": "{}"}}"#,
-                    error.to_string()
-                );
-            },
-
-            Error::ImageError(error) => {
-                error!(
-                    r#"{{"error": ImageError, "message": "{}"}}"#,
-                    error.to_string()
-                );
-            },
-
-            Error::ChobitMapError(error) => {
-                error!("{}", error);
-            },
-
-            Error::ChobitAniValueError(error) => {
-                error!("{}", error);
-            },
-
-            Error::YamlError(error) => {
-                error!(
-                    r#"{{"error": YamlError, "message": "{}"}}"#,
-                    error.to_string()
-                );
-            },
-
-            Error::NoSkin {id} => {
-                error!(
-                    r#"{{"error": NoSkin, "id", {}}}"#,
-                    id
-                );
-            },
-
-            Error::LoadError(error) => {
-                error!("{}", error);
-            },
-
-            Error::ApplicationError(error) => {
-                error!("{}", error);
-            },
-
-            Error::BorrowMutError(error) => {
-                error!("BorrowMutError: {}", error);
-            }
+            Error::IOError(error) => write!(
+                formatter,
+                r#"{{"error": IOError, "message": "{}"}}"#,
+                error
+            ),
+
+            Error::ImageError(error) => write!(
+                formatter,
+                r#"{{"error": ImageError, "message": "{}"}}"#,
+                error
+            ),
+
+            Error::ChobitMapError(error) => write!(formatter, "{}", error),
+
+            Error::ChobitAniValueError(error) => write!(formatter, "{}", error),
+
+            Error::YamlError(error) => write!(
+                formatter,
+                r#"{{"error": YamlError, "message": "{}"}}"#,
+                error
+            ),
+
+            Error::NoSkin {name} => write!(
+                formatter,
+                r#"{{"error": NoSkin, "name": {:?}}}"#,
+                name
+            ),
+
+            Error::LoadError(error) => write!(formatter, "{}", error),
+
+            Error::ApplicationError(error) => write!(formatter, "{}", error),
+
+            Error::BorrowMutError(error) =>
+                write!(formatter, "BorrowMutError: {}", error)
         }
     }
 }
+
+impl Error {
+    #[inline]
+    pub fn error_log(&self) {
+        error!("{}", self);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::state::{State, Button};
+
+use eframe::egui::{Ui, Key};
+
+const KEYS: [Key; 73] = [
+    Key::ArrowDown, Key::ArrowLeft, Key::ArrowRight, Key::ArrowUp,
+    Key::Escape, Key::Tab, Key::Backspace, Key::Enter, Key::Space,
+    Key::Insert, Key::Delete, Key::Home, Key::End, Key::PageUp, Key::PageDown,
+    Key::Minus, Key::PlusEquals,
+    Key::Num0, Key::Num1, Key::Num2, Key::Num3, Key::Num4,
+    Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9,
+    Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I,
+    Key::J, Key::K, Key::L, Key::M, Key::N, Key::O, Key::P, Key::Q, Key::R,
+    Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z,
+    Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7,
+    Key::F8, Key::F9, Key::F10, Key::F11, Key::F12, Key::F13, Key::F14,
+    Key::F15, Key::F16, Key::F17, Key::F18, Key::F19, Key::F20
+];
+
+pub const DEFAULT_KEY_BINDINGS: [(Key, Button); 5] = [
+    (Key::Space, Button::StartStop),
+    (Key::R, Button::Reset),
+    (Key::Tab, Button::Switch),
+    (Key::L, Button::Lap),
+    (Key::Escape, Button::Quit)
+];
+
+/// Feeds pressed keys into `State::clicked_btn` like button views.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    bindings: Vec<(Key, Button)>
+}
+
+impl KeyBindings {
+    #[inline]
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(bindings: Vec<(Key, Button)>) -> Self {
+        Self {
+            bindings: bindings
+        }
+    }
+
+    /// Finds a key by its name. (e.g. "Space", "R", "Escape", "F1")
+    pub fn find_key(name: &str) -> Option<Key> {
+        KEYS.iter().find(
+            |key| key.name().eq_ignore_ascii_case(name)
+        ).copied()
+    }
+
+    pub fn ready(&self, ui: &Ui, state: &mut State) {
+        ui.input(|i_state| {
+            if i_state.modifiers.any() {
+                return;
+            }
+
+            for (key, button) in self.bindings.iter() {
+                if i_state.key_pressed(*key) {
+                    state.clicked_btn = Some(*button);
+                    break;
+                }
+            }
+        });
+    }
+}
+
+impl Default for KeyBindings {
+    #[inline]
+    fn default() -> Self {
+        Self::new(DEFAULT_KEY_BINDINGS.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eframe::egui::{Context, CentralPanel, RawInput, Event, Modifiers};
+
+    #[allow(clippy::redundant_field_names)]
+    fn press_key(
+        key_bindings: &KeyBindings,
+        key: Key,
+        modifiers: Modifiers
+    ) -> Option<Button> {
+        let mut state = State::default();
+
+        let raw_input = RawInput {
+            events: vec![Event::Key {
+                key: key,
+                pressed: true,
+                repeat: false,
+                modifiers: modifiers
+            }],
+            modifiers: modifiers,
+            ..Default::default()
+        };
+
+        // the first frame gains focus and clears modifiers.
+        let ctx = Context::default();
+        let _ = ctx.run(RawInput::default(), |_| {});
+
+        let _ = ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                key_bindings.ready(ui, &mut state);
+            });
+        });
+
+        state.clicked_btn
+    }
+
+    #[test]
+    fn ready_test() {
+        let key_bindings = KeyBindings::default();
+
+        assert_eq!(
+            press_key(&key_bindings, Key::Space, Modifiers::NONE),
+            Some(Button::StartStop)
+        );
+
+        assert_eq!(
+            press_key(&key_bindings, Key::R, Modifiers::NONE),
+            Some(Button::Reset)
+        );
+
+        assert_eq!(press_key(&key_bindings, Key::Q, Modifiers::NONE), None);
+
+        // keys with modifiers are left to the system.
+        assert_eq!(press_key(&key_bindings, Key::R, Modifiers::CTRL), None);
+
+        let key_bindings = KeyBindings::new(vec![]);
+
+        assert_eq!(
+            press_key(&key_bindings, Key::Space, Modifiers::NONE),
+            None
+        );
+    }
+
+    #[test]
+    fn find_key_test() {
+        for key in KEYS {
+            assert_eq!(KeyBindings::find_key(key.name()), Some(key));
+        }
+
+        assert_eq!(KeyBindings::find_key("space"), Some(Key::Space));
+        assert_eq!(KeyBindings::find_key("esc"), None);
+    }
+}
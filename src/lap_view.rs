@@ -0,0 +1,95 @@
+use crate::{
+    error::Error,
+    state::{State, WatchMode},
+    view::View,
+    display_view::{DisplayView, DisplayNumber}
+};
+
+use eframe::egui::{Ui, TextureId};
+
+pub struct LapRow {
+    number: Option<DisplayNumber>,
+    split: Option<DisplayView>,
+    lap: Option<DisplayView>
+}
+
+impl LapRow {
+    #[inline]
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(
+        number: Option<DisplayNumber>,
+        split: Option<DisplayView>,
+        lap: Option<DisplayView>
+    ) -> Self {
+        Self {
+            number: number,
+            split: split,
+            lap: lap
+        }
+    }
+}
+
+pub struct LapView {
+    depth: i32,
+
+    texture_id: TextureId,
+
+    // rows[0] shows the latest lap.
+    rows: Vec<LapRow>
+}
+
+impl LapView {
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(
+        depth: i32,
+        texture_id: TextureId,
+        rows: Vec<LapRow>
+    ) -> Self {
+        Self {
+            depth: depth,
+
+            texture_id: texture_id,
+
+            rows: rows
+        }
+    }
+}
+
+impl View for LapView {
+    #[inline]
+    fn depth(&self) -> i32 {self.depth}
+
+    fn ready(&mut self, _ui: &Ui, _state: &mut State) -> Result<(), Error> {
+        // nothing to do.
+        Ok(())
+    }
+
+    fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
+        if !matches!(state.watch_mode, WatchMode::Stopwatch(..)) {
+            return Ok(());
+        }
+
+        let laps = state.laps.iter().enumerate().rev();
+
+        for (row, (index, lap_time)) in self.rows.iter_mut().zip(laps) {
+            if let Some(number) = row.number.as_mut() {
+                number.show(ui, self.texture_id, (index + 1) as u32)?;
+            }
+
+            if let Some(split) = row.split.as_mut() {
+                split.show_time(ui, &lap_time.split)?;
+            }
+
+            if let Some(lap) = row.lap.as_mut() {
+                lap.show_time(ui, &lap_time.lap)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn init(&mut self, _state: &mut State) -> Result<(), Error> {
+        // pass
+        Ok(())
+    }
+}
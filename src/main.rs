@@ -15,23 +15,37 @@ mod error;
 use self::error::Error;
 
 mod state;
+mod time_zone;
 mod model;
 
 mod view;
 mod display_view;
 mod switch_btn_view;
 mod button_view;
+mod lap_view;
+mod phase_view;
+mod am_pm_view;
+mod separator_view;
+mod world_clock_view;
 mod animation_view;
 
 mod skin;
+mod key_bindings;
 
 mod settings;
 mod save_data;
 mod settings_loader;
+mod settings_watcher;
 
 mod application;
 use self::application::ApplicationError;
 
+mod save_data_command;
+use self::save_data_command::SaveDataCommand;
+
+mod settings_checker;
+use self::settings_checker::SettingsChecker;
+
 use std::{
     process::ExitCode,
     rc::Rc,
@@ -53,6 +67,16 @@ fn main() -> ExitCode {
 
     let args: Vec<String> = env::args().collect();
 
+    if SaveDataCommand::is_requested(&args) {
+        attach_parent_console();
+        return SaveDataCommand::run(&args);
+    }
+
+    if SettingsChecker::is_requested(&args) {
+        attach_parent_console();
+        return SettingsChecker::run(&args);
+    }
+
     let exit_code = Rc::new(RefCell::new(ExitCode::SUCCESS));
 
     let options = NativeOptions {
@@ -87,6 +111,7 @@ fn main() -> ExitCode {
                     Error::ApplicationError(
                         ApplicationError::OnlyShowHelp(help)
                     ) => {
+                        attach_parent_console();
                         eprintln!("{}", help);
                         *exit_code_3.borrow_mut() = ExitCode::SUCCESS;
                         Box::new(DummyApplication)
@@ -95,6 +120,7 @@ fn main() -> ExitCode {
                     Error::ApplicationError(
                         ApplicationError::CommandOptionError(help)
                     ) => {
+                        attach_parent_console();
                         eprintln!("{}", help);
                         *exit_code_3.borrow_mut() = ExitCode::FAILURE;
                         Box::new(DummyApplication)
@@ -119,3 +145,26 @@ fn main() -> ExitCode {
         }
     }
 }
+
+// Release builds on Windows have no console of their own,
+// so command line output goes to the console of the parent process.
+#[cfg(windows)]
+fn attach_parent_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+
+    // fails if the parent has no console, like Explorer. nothing to do.
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+#[cfg(not(windows))]
+#[inline]
+fn attach_parent_console() {
+    // the console is inherited.
+}
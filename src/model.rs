@@ -19,7 +19,23 @@ impl Model {
         &self,
         state: &mut State
     ) {
-        let skin_id = state.find_skin_id_from_stopwatch_events();
+        let skin_id = state.skin_overrides.stopwatch.unwrap_or_else(
+            || state.find_skin_id_from_stopwatch_events()
+        );
+
+        if state.current_skin_id != skin_id {
+            state.change_skin_request = true;
+            state.current_skin_id = skin_id;
+        }
+    }
+
+    fn update_timer_skin_id(
+        &self,
+        state: &mut State
+    ) {
+        let skin_id = state.skin_overrides.timer.unwrap_or_else(
+            || state.find_skin_id_from_timer_events()
+        );
 
         if state.current_skin_id != skin_id {
             state.change_skin_request = true;
@@ -31,7 +47,10 @@ impl Model {
         &self,
         state: &mut State
     ) {
-        let skin_id = state.find_skin_id_from_clock_events();
+        // the ringing alarm takes precedence over the chosen skin.
+        let skin_id = state.find_skin_id_from_alarm()
+            .or(state.skin_overrides.clock)
+            .unwrap_or_else(|| state.find_skin_id_from_clock_events());
 
         if state.current_skin_id != skin_id {
             state.change_skin_request = true;
@@ -44,6 +63,11 @@ impl Model {
         state.clicked_btn = None;
         state.dt = 0.0;
         state.quit_request = false;
+        state.save_request = false;
+        state.minimize_request = false;
+        state.reload_request = false;
+        state.alarm_sound_request = None;
+        state.alarm_stop_request = false;
         state.change_skin_request = true;
 
         match state.watch_mode {
@@ -52,6 +76,11 @@ impl Model {
                 Ok(())
             },
 
+            WatchMode::Timer(..) => {
+                self.update_timer_skin_id(state);
+                Ok(())
+            },
+
             WatchMode::Clock => {
                 state.set_clock();
 
@@ -66,6 +95,11 @@ impl Model {
     pub fn preproc(&mut self, state: &mut State) -> Result<(), Error> {
         state.clicked_btn = None;
         state.change_skin_request = false;
+        state.save_request = false;
+        state.minimize_request = false;
+        state.reload_request = false;
+        state.alarm_sound_request = None;
+        state.alarm_stop_request = false;
 
         Ok(())
     }
@@ -74,14 +108,154 @@ impl Model {
     pub fn update(&mut self, state: &mut State) -> Result<(), Error> {
         state.change_skin_request = false;
 
+        self.update_autosave(state);
+        self.update_window_buttons(state);
+
         match state.watch_mode {
             WatchMode::Stopwatch(stopwatch_mode) =>
                 self.update_stopwatch_mode(state, stopwatch_mode),
 
+            WatchMode::Timer(timer_mode) =>
+                self.update_timer_mode(state, timer_mode),
+
             WatchMode::Clock => self.update_clock_mode(state),
         }
     }
 
+    fn update_autosave(&self, state: &mut State) {
+        if let Some(interval) = state.autosave_interval {
+            let elapsed = state.now.saturating_duration_since(
+                state.last_save_time
+            );
+
+            if elapsed >= interval {
+                state.save_request = true;
+                state.last_save_time = state.now;
+            }
+        }
+    }
+
+    // These buttons work in every watch mode.
+    // The click is consumed so mode handlers see no click.
+    fn update_window_buttons(&self, state: &mut State) {
+        match state.clicked_btn {
+            Some(Button::NextSkin) => {
+                *state.skin_overrides.get_mut(state.watch_mode) =
+                    state.next_skin_id();
+            },
+
+            Some(Button::PrevSkin) => {
+                *state.skin_overrides.get_mut(state.watch_mode) =
+                    state.prev_skin_id();
+            },
+
+            Some(Button::SelectSkin(skin_id)) => {
+                *state.skin_overrides.get_mut(state.watch_mode) =
+                    Some(skin_id);
+            },
+
+            Some(Button::ClearSkin) => {
+                *state.skin_overrides.get_mut(state.watch_mode) = None;
+            },
+
+            Some(Button::ToggleAlwaysOnTop) => {
+                state.always_on_top = !state.always_on_top;
+            },
+
+            Some(Button::Minimize) => {
+                state.minimize_request = true;
+            },
+
+            Some(Button::DismissAlarm) => {
+                self.dismiss_alarm(state);
+            },
+
+            Some(Button::SnoozeAlarm) => {
+                self.snooze_alarm(state);
+            },
+
+            _ => {return;}
+        }
+
+        state.clicked_btn = None;
+    }
+
+    // Alarms are checked only in clock mode.
+    fn update_alarms(&self, state: &mut State) {
+        if let AlarmState::Snoozed {index, until} = state.alarm_state {
+            if state.now >= until {
+                self.ring_alarm(state, index);
+            }
+        }
+
+        if let Some(checked_at) = state.alarm_checked_at.take() {
+            if let Some(index) = state.find_due_alarm(&checked_at) {
+                self.ring_alarm(state, index);
+            }
+        }
+
+        state.alarm_checked_at =
+            Some((state.current_date, state.current_clock_time.clone()));
+    }
+
+    fn ring_alarm(&self, state: &mut State, index: usize) {
+        if state.alarm_state != AlarmState::Idle {
+            state.alarm_stop_request = true;
+        }
+
+        state.alarm_state = AlarmState::Ringing(index);
+        state.alarm_sound_request =
+            state.alarms.get(index).and_then(|alarm| alarm.sound.clone());
+    }
+
+    fn dismiss_alarm(&self, state: &mut State) {
+        if state.alarm_state != AlarmState::Idle {
+            state.alarm_state = AlarmState::Idle;
+            state.alarm_stop_request = true;
+        }
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    fn snooze_alarm(&self, state: &mut State) {
+        if let AlarmState::Ringing(index) = state.alarm_state {
+            state.alarm_state = AlarmState::Snoozed {
+                index: index,
+                until: state.now + state.alarm_snooze
+            };
+            state.alarm_stop_request = true;
+        }
+    }
+
+    fn change_to_stopwatch_mode(&mut self, state: &mut State) {
+        self.update_stopwatch_skin_id(state);
+
+        state.watch_mode = WatchMode::Stopwatch(
+            StopwatchMode::Stopped
+        );
+    }
+
+    fn change_to_timer_mode(&mut self, state: &mut State) {
+        state.watch_mode = WatchMode::Timer(
+            if state.current_timer_time == WatchTime::default() {
+                TimerMode::Expired
+            } else {
+                TimerMode::Stopped
+            }
+        );
+
+        self.update_timer_skin_id(state);
+    }
+
+    fn change_to_clock_mode(&mut self, state: &mut State) {
+        // doesn't ring alarms of the time spent in other modes.
+        state.alarm_checked_at = None;
+
+        state.set_clock();
+        self.update_clock_skin_id(state);
+
+        state.watch_mode = WatchMode::Clock;
+    }
+
     #[inline]
     fn update_stopwatch_mode(
         &mut self,
@@ -97,6 +271,18 @@ impl Model {
         }
     }
 
+    #[inline]
+    fn switch_from_stopwatch_mode(&mut self, state: &mut State) {
+        state.stopwatch_elapsed.stop(state.now);
+        state.update_stopwatch_time();
+
+        if state.timer_is_enabled() {
+            self.change_to_timer_mode(state);
+        } else {
+            self.change_to_clock_mode(state);
+        }
+    }
+
     fn update_stopwatch_running_mode(
         &mut self,
         state: &mut State
@@ -104,15 +290,15 @@ impl Model {
         match state.clicked_btn {
             Some(btn_mode) =>  match btn_mode {
                 Button::Switch => {
-                    state.set_clock();
-                    self.update_clock_skin_id(state);
-
-                    state.watch_mode = WatchMode::Clock;
+                    self.switch_from_stopwatch_mode(state);
 
                     Ok(())
                 },
 
                 Button::StartStop => {
+                    state.stopwatch_elapsed.stop(state.now);
+                    state.update_stopwatch_time();
+
                     self.update_stopwatch_skin_id(state);
 
                     state.watch_mode = WatchMode::Stopwatch(
@@ -123,8 +309,18 @@ impl Model {
                 },
 
                 Button::Reset => {
-                    state.current_stopwatch_time = Default::default();
-                    state.saved_time = 0.0;
+                    state.stopwatch_elapsed.reset(state.now);
+                    state.update_stopwatch_time();
+                    state.laps.clear();
+
+                    self.update_stopwatch_skin_id(state);
+
+                    Ok(())
+                },
+
+                Button::Lap => {
+                    state.update_stopwatch_time();
+                    state.record_lap();
 
                     self.update_stopwatch_skin_id(state);
 
@@ -133,12 +329,19 @@ impl Model {
 
                 Button::Quit => {
                     self.quick_button_clicked(state)
+                },
+
+                // handled by update_window_buttons().
+                _ => {
+                    self.update_running_stopwatch_time(state);
+                    self.update_stopwatch_skin_id(state);
+
+                    Ok(())
                 }
-                
             },
 
             None => {
-                state.add_stopwatch_time();
+                self.update_running_stopwatch_time(state);
                 self.update_stopwatch_skin_id(state);
 
                 Ok(())
@@ -146,6 +349,20 @@ impl Model {
         }
     }
 
+    // stops the stopwatch if it has reached the limit.
+    // (OverflowPolicy::Stop)
+    fn update_running_stopwatch_time(&mut self, state: &mut State) {
+        if state.update_stopwatch_time() {
+            state.stopwatch_elapsed.stop(state.now);
+            state.stopwatch_elapsed.set_elapsed(
+                state.now,
+                state.current_stopwatch_time.to_duration()
+            );
+
+            state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Stopped);
+        }
+    }
+
     fn update_stopwatch_stopped_mode(
         &mut self,
         state: &mut State
@@ -153,15 +370,14 @@ impl Model {
         match state.clicked_btn {
             Some(btn_mode) =>  match btn_mode {
                 Button::Switch => {
-                    state.set_clock();
-                    self.update_clock_skin_id(state);
-
-                    state.watch_mode = WatchMode::Clock;
+                    self.switch_from_stopwatch_mode(state);
 
                     Ok(())
                 },
 
                 Button::StartStop => {
+                    state.stopwatch_elapsed.start(state.now);
+
                     self.update_stopwatch_skin_id(state);
 
                     state.watch_mode = WatchMode::Stopwatch(
@@ -172,15 +388,29 @@ impl Model {
                 },
 
                 Button::Reset => {
-                    state.current_stopwatch_time = Default::default();
-                    state.saved_time = 0.0;
+                    state.stopwatch_elapsed.reset(state.now);
+                    state.update_stopwatch_time();
+                    state.laps.clear();
 
                     self.update_stopwatch_skin_id(state);
 
                     Ok(())
                 },
 
-                Button::Quit => self.quick_button_clicked(state)
+                Button::Lap => {
+                    self.update_stopwatch_skin_id(state);
+
+                    Ok(())
+                },
+
+                Button::Quit => self.quick_button_clicked(state),
+
+                // handled by update_window_buttons().
+                _ => {
+                    self.update_stopwatch_skin_id(state);
+
+                    Ok(())
+                }
             },
 
             None => {
@@ -191,15 +421,168 @@ impl Model {
         }
     }
 
-    fn update_clock_mode(&mut self, state: &mut State) -> Result<(), Error> {
+    #[inline]
+    fn update_timer_mode(
+        &mut self,
+        state: &mut State,
+        timer_mode: TimerMode
+    ) -> Result<(), Error> {
+        match timer_mode {
+            TimerMode::Running => self.update_timer_running_mode(state),
+
+            TimerMode::Stopped => self.update_timer_stopped_mode(state),
+
+            TimerMode::Expired => self.update_timer_expired_mode(state)
+        }
+    }
+
+    fn update_timer_running_mode(
+        &mut self,
+        state: &mut State
+    ) -> Result<(), Error> {
         match state.clicked_btn {
             Some(btn_mode) =>  match btn_mode {
                 Button::Switch => {
-                    self.update_stopwatch_skin_id(state);
+                    state.timer_elapsed.stop(state.now);
+                    state.update_timer_time();
 
-                    state.watch_mode = WatchMode::Stopwatch(
-                        StopwatchMode::Stopped
-                    );
+                    self.change_to_clock_mode(state);
+
+                    Ok(())
+                },
+
+                Button::StartStop => {
+                    state.timer_elapsed.stop(state.now);
+                    state.update_timer_time();
+
+                    state.watch_mode = WatchMode::Timer(TimerMode::Stopped);
+
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                },
+
+                Button::Reset => {
+                    state.reset_timer();
+
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                },
+
+                Button::Quit => self.quick_button_clicked(state),
+
+                _ => {
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                }
+            },
+
+            None => {
+                // expires after the last phase.
+                if state.update_timer_time() && !state.next_timer_phase() {
+                    state.timer_elapsed.stop(state.now);
+                    state.watch_mode = WatchMode::Timer(TimerMode::Expired);
+                }
+
+                self.update_timer_skin_id(state);
+
+                Ok(())
+            }
+        }
+    }
+
+    fn update_timer_stopped_mode(
+        &mut self,
+        state: &mut State
+    ) -> Result<(), Error> {
+        match state.clicked_btn {
+            Some(btn_mode) =>  match btn_mode {
+                Button::Switch => {
+                    self.change_to_clock_mode(state);
+
+                    Ok(())
+                },
+
+                Button::StartStop => {
+                    state.timer_elapsed.start(state.now);
+
+                    state.watch_mode = WatchMode::Timer(TimerMode::Running);
+
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                },
+
+                Button::Reset => {
+                    state.reset_timer();
+
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                },
+
+                Button::Quit => self.quick_button_clicked(state),
+
+                _ => {
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                }
+            },
+
+            None => {
+                self.update_timer_skin_id(state);
+
+                Ok(())
+            }
+        }
+    }
+
+    fn update_timer_expired_mode(
+        &mut self,
+        state: &mut State
+    ) -> Result<(), Error> {
+        match state.clicked_btn {
+            Some(btn_mode) =>  match btn_mode {
+                Button::Switch => {
+                    self.change_to_clock_mode(state);
+
+                    Ok(())
+                },
+
+                Button::StartStop | Button::Reset => {
+                    state.reset_timer();
+                    state.watch_mode = WatchMode::Timer(TimerMode::Stopped);
+
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                },
+
+                Button::Quit => self.quick_button_clicked(state),
+
+                _ => {
+                    self.update_timer_skin_id(state);
+
+                    Ok(())
+                }
+            },
+
+            None => {
+                self.update_timer_skin_id(state);
+
+                Ok(())
+            }
+        }
+    }
+
+    fn update_clock_mode(&mut self, state: &mut State) -> Result<(), Error> {
+        match state.clicked_btn {
+            Some(btn_mode) =>  match btn_mode {
+                Button::Switch => {
+                    self.change_to_stopwatch_mode(state);
 
                     Ok(())
                 },
@@ -208,6 +591,7 @@ impl Model {
 
                 _ => {
                     state.set_clock();
+                    self.update_alarms(state);
 
                     self.update_clock_skin_id(state);
 
@@ -217,6 +601,7 @@ impl Model {
 
             None => {
                 state.set_clock();
+                self.update_alarms(state);
 
                 self.update_clock_skin_id(state);
 
@@ -236,8 +621,10 @@ impl Model {
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn clock_test_1() {
@@ -269,8 +656,9 @@ mod tests {
     fn stopwatch_running_test_1() {
         let mut state = State::default();
         state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Running);
+        state.stopwatch_elapsed.start(state.now);
 
-        state.dt = 0.01;
+        let dt = Duration::from_millis(10);
 
         let mut model = Model::new();
 
@@ -281,6 +669,7 @@ mod tests {
                 state.clicked_btn = Some(Button::Quit);
             }
 
+            state.now += dt;
             assert!(model.update(&mut state).is_ok());
             if state.quit_request {break;}
         }
@@ -293,7 +682,7 @@ mod tests {
         let mut state = State::default();
         state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Stopped);
 
-        state.dt = 0.01;
+        let dt = Duration::from_millis(10);
         let check = state.current_stopwatch_time.clone();
 
         let mut model = Model::new();
@@ -305,6 +694,7 @@ mod tests {
                 state.clicked_btn = Some(Button::Quit);
             }
 
+            state.now += dt;
             assert!(model.update(&mut state).is_ok());
             if state.quit_request {break;}
 
@@ -337,10 +727,14 @@ mod tests {
         );
         assert_eq!(state.current_stopwatch_time, check);
 
-        state.dt = 0.1;
+        let dt = Duration::from_millis(100);
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
         assert_eq!(state.current_stopwatch_time, check);
 
@@ -354,9 +748,13 @@ mod tests {
         );
         assert_eq!(state.current_stopwatch_time, check);
 
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
         assert!(state.current_stopwatch_time > check);
         let check_2 = state.current_stopwatch_time.clone();
@@ -366,9 +764,13 @@ mod tests {
         state.clicked_btn = None;
         assert_eq!(state.current_stopwatch_time, check);
 
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
         assert!(state.current_stopwatch_time > check);
         assert_eq!(state.current_stopwatch_time, check_2);
@@ -377,9 +779,13 @@ mod tests {
         assert!(model.update(&mut state).is_ok());
         state.clicked_btn = None;
 
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
         assert_eq!(state.current_stopwatch_time, check_2);
 
@@ -392,6 +798,7 @@ mod tests {
             WatchMode::Clock
         );
 
+        state.now += dt;
         assert!(model.update(&mut state).is_ok());
         assert_ne!(state.current_clock_time, check);
         assert_eq!(state.current_stopwatch_time, check_2);
@@ -427,4 +834,496 @@ mod tests {
 
         assert!(state.quit_request);
     }
+
+    #[test]
+    fn lap_test_1() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Running);
+        state.stopwatch_elapsed.start(state.now);
+
+        let dt = Duration::from_millis(10);
+
+        let mut model = Model::new();
+
+        for _ in 0..3 {
+            for _ in 0..100 {
+                state.now += dt;
+                assert!(model.update(&mut state).is_ok());
+            }
+
+            state.clicked_btn = Some(Button::Lap);
+            assert!(model.update(&mut state).is_ok());
+            state.clicked_btn = None;
+        }
+
+        assert_eq!(state.laps.len(), 3);
+        assert_eq!(state.laps[2].split, state.current_stopwatch_time);
+
+        state.clicked_btn = Some(Button::StartStop);
+        assert!(model.update(&mut state).is_ok());
+        state.clicked_btn = None;
+
+        state.clicked_btn = Some(Button::Lap);
+        assert!(model.update(&mut state).is_ok());
+        state.clicked_btn = None;
+
+        assert_eq!(state.laps.len(), 3);
+
+        state.clicked_btn = Some(Button::Reset);
+        assert!(model.update(&mut state).is_ok());
+        state.clicked_btn = None;
+
+        assert!(state.laps.is_empty());
+    }
+
+    fn gen_timer_state() -> State {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Clock;
+
+        state.timer_duration = Some(WatchTime {
+            hours: 0,
+            minutes: 0,
+            seconds: 1,
+            cents: 0
+        });
+        state.reset_timer();
+
+        state
+    }
+
+    #[test]
+    fn switch_test_1() {
+        let mut state = gen_timer_state();
+
+        let mut model = Model::new();
+
+        state.clicked_btn = Some(Button::Switch);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(
+            state.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Stopped)
+        );
+
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Stopped));
+
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.watch_mode, WatchMode::Clock);
+
+        state.timer_duration = None;
+
+        assert!(model.update(&mut state).is_ok());
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.watch_mode, WatchMode::Clock);
+    }
+
+    #[test]
+    fn timer_test_1() {
+        let mut state = gen_timer_state();
+        state.watch_mode = WatchMode::Timer(TimerMode::Stopped);
+
+        let dt = Duration::from_millis(10);
+
+        let mut model = Model::new();
+
+        for _ in 0..10 {
+            state.now += dt;
+            assert!(model.update(&mut state).is_ok());
+        }
+        assert_eq!(state.current_timer_time.seconds, 1);
+
+        state.clicked_btn = Some(Button::StartStop);
+        assert!(model.update(&mut state).is_ok());
+        state.clicked_btn = None;
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Running));
+
+        let mut count: usize = 0;
+
+        while state.watch_mode == WatchMode::Timer(TimerMode::Running) {
+            state.now += dt;
+            assert!(model.update(&mut state).is_ok());
+
+            count += 1;
+            assert!(count <= 110);
+        }
+
+        assert!(count >= 90);
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Expired));
+        assert_eq!(state.current_timer_time, WatchTime::default());
+
+        state.clicked_btn = Some(Button::StartStop);
+        assert!(model.update(&mut state).is_ok());
+        state.clicked_btn = None;
+
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Stopped));
+        assert_eq!(state.current_timer_time.seconds, 1);
+    }
+
+    #[test]
+    fn timer_expired_skin_test_1() {
+        let mut state = gen_timer_state();
+        state.watch_mode = WatchMode::Timer(TimerMode::Running);
+        state.timer_elapsed.start(state.now);
+
+        state.default_timer_skin_id = 1;
+        state.timer_expired_skin_id = Some(2);
+
+        let mut model = Model::new();
+
+        assert!(model.init(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 1);
+
+        let dt = Duration::from_millis(500);
+
+        state.now += dt;
+        assert!(model.update(&mut state).is_ok());
+        assert!(!state.change_skin_request);
+
+        state.now += dt;
+        assert!(model.update(&mut state).is_ok());
+        assert!(state.change_skin_request);
+        assert_eq!(state.current_skin_id, 2);
+    }
+
+    #[test]
+    fn autosave_test_1() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Clock;
+
+        let mut model = Model::new();
+
+        let dt = Duration::from_millis(400);
+
+        for _ in 0..10 {
+            assert!(model.preproc(&mut state).is_ok());
+            state.now += dt;
+            assert!(model.update(&mut state).is_ok());
+            assert!(!state.save_request);
+        }
+
+        state.autosave_interval = Some(Duration::from_secs(1));
+        state.last_save_time = state.now;
+
+        let mut count: usize = 0;
+
+        for _ in 0..10 {
+            assert!(model.preproc(&mut state).is_ok());
+            state.now += dt;
+            assert!(model.update(&mut state).is_ok());
+
+            if state.save_request {
+                count += 1;
+            }
+        }
+
+        // saves at 1.2, 2.4 and 3.6 seconds.
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn window_buttons_test_1() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Stopped);
+        state.skin_ids = vec![1, 2, 3];
+        state.default_stopwatch_skin_id = 1;
+        state.default_clock_skin_id = 1;
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 1);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::NextSkin);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 2);
+        assert!(state.change_skin_request);
+        assert_eq!(
+            state.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Stopped)
+        );
+
+        // keeps the chosen skin.
+        assert!(model.preproc(&mut state).is_ok());
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 2);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::NextSkin);
+        assert!(model.update(&mut state).is_ok());
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::NextSkin);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 1);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::NextSkin);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 2);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::SelectSkin(3));
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 3);
+
+        // skins are chosen for each mode.
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::Switch);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.watch_mode, WatchMode::Clock);
+        assert_eq!(state.current_skin_id, 1);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::PrevSkin);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 3);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::PrevSkin);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 2);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::Switch);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(
+            state.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Stopped)
+        );
+        assert_eq!(state.current_skin_id, 3);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::ClearSkin);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 1);
+
+        assert_eq!(state.skin_overrides, SkinOverrides {
+            stopwatch: None,
+            timer: None,
+            clock: Some(2)
+        });
+    }
+
+    #[test]
+    fn select_skin_test_1() {
+        let mut state = gen_timer_state();
+        state.watch_mode = WatchMode::Timer(TimerMode::Stopped);
+        state.skin_ids = vec![1, 2, 3];
+        state.default_stopwatch_skin_id = 1;
+        state.default_timer_skin_id = 1;
+        state.default_clock_skin_id = 1;
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 1);
+
+        // the context menu picks a skin by id.
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::SelectSkin(3));
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 3);
+        assert!(state.change_skin_request);
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Stopped));
+
+        assert_eq!(state.skin_overrides, SkinOverrides {
+            stopwatch: None,
+            timer: Some(3),
+            clock: None
+        });
+
+        // the override is kept while the timer runs.
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::StartStop);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Running));
+        assert_eq!(state.current_skin_id, 3);
+    }
+
+    #[test]
+    fn window_buttons_test_2() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Clock;
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+        assert!(state.always_on_top);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::ToggleAlwaysOnTop);
+        assert!(model.update(&mut state).is_ok());
+        assert!(!state.always_on_top);
+        assert!(!state.minimize_request);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::Minimize);
+        assert!(model.update(&mut state).is_ok());
+        assert!(!state.always_on_top);
+        assert!(state.minimize_request);
+        assert!(!state.quit_request);
+
+        assert!(model.preproc(&mut state).is_ok());
+        assert!(!state.minimize_request);
+    }
+
+    #[test]
+    fn alarm_test_1() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Clock;
+        state.default_clock_skin_id = 1;
+        state.alarms = vec![Alarm {
+            time: WatchTime::default(),
+            weekdays: Vec::new(),
+            skin_id: Some(2),
+            sound: Some("alarm.wav".into())
+        }];
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 1);
+
+        // snooze has ended.
+        state.alarm_state = AlarmState::Snoozed {
+            index: 0,
+            until: Instant::now()
+        };
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.now = Instant::now();
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.alarm_state, AlarmState::Ringing(0));
+        assert_eq!(state.alarm_sound_request, Some("alarm.wav".into()));
+        assert!(state.alarm_stop_request);
+        assert_eq!(state.current_skin_id, 2);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::SnoozeAlarm);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.alarm_state, AlarmState::Snoozed {
+            index: 0,
+            until: state.now + state.alarm_snooze
+        });
+        assert_eq!(state.alarm_sound_request, None);
+        assert!(state.alarm_stop_request);
+        assert_eq!(state.current_skin_id, 1);
+
+        // not ringing yet.
+        assert!(model.preproc(&mut state).is_ok());
+        assert!(model.update(&mut state).is_ok());
+        assert!(!state.alarm_stop_request);
+
+        // only a ringing alarm can be snoozed.
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::SnoozeAlarm);
+        assert!(model.update(&mut state).is_ok());
+        assert!(!state.alarm_stop_request);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::DismissAlarm);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.alarm_state, AlarmState::Idle);
+        assert!(state.alarm_stop_request);
+
+        assert!(model.preproc(&mut state).is_ok());
+        state.clicked_btn = Some(Button::DismissAlarm);
+        assert!(model.update(&mut state).is_ok());
+        assert!(!state.alarm_stop_request);
+    }
+
+    #[test]
+    fn timer_phases_test_1() {
+        let mut state = gen_timer_state();
+        state.watch_mode = WatchMode::Timer(TimerMode::Stopped);
+        state.default_timer_skin_id = 1;
+        state.timer_phases = vec![
+            TimerPhase {
+                duration: WatchTime {
+                    hours: 0,
+                    minutes: 0,
+                    seconds: 1,
+                    cents: 0
+                },
+                skin_id: Some(2)
+            },
+            TimerPhase {
+                duration: WatchTime {
+                    hours: 0,
+                    minutes: 0,
+                    seconds: 0,
+                    cents: 50
+                },
+                skin_id: Some(3)
+            }
+        ];
+        state.reset_timer();
+
+        let dt = Duration::from_millis(10);
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+        assert_eq!(state.current_skin_id, 2);
+
+        state.clicked_btn = Some(Button::StartStop);
+        assert!(model.update(&mut state).is_ok());
+        state.clicked_btn = None;
+
+        while state.timer_phase == 0 {
+            state.now += dt;
+            assert!(model.update(&mut state).is_ok());
+        }
+
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Running));
+        assert_eq!(state.current_skin_id, 3);
+
+        while state.watch_mode == WatchMode::Timer(TimerMode::Running) {
+            state.now += dt;
+            assert!(model.update(&mut state).is_ok());
+        }
+
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Expired));
+        assert_eq!(state.timer_phase, 1);
+
+        // starts again from the first phase.
+        state.clicked_btn = Some(Button::StartStop);
+        assert!(model.update(&mut state).is_ok());
+        state.clicked_btn = None;
+
+        assert_eq!(state.watch_mode, WatchMode::Timer(TimerMode::Stopped));
+        assert_eq!(state.timer_phase, 0);
+        assert_eq!(state.current_timer_time.seconds, 1);
+        assert_eq!(state.current_skin_id, 2);
+    }
+
+    #[test]
+    fn stopwatch_overflow_test_1() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Running);
+        state.stopwatch_limit = WatchTime {
+            hours: 0,
+            minutes: 0,
+            seconds: 1,
+            cents: 0
+        };
+        state.stopwatch_overflow = OverflowPolicy::Stop;
+        state.stopwatch_elapsed.start(state.now);
+
+        let mut model = Model::new();
+        assert!(model.init(&mut state).is_ok());
+
+        while state.watch_mode
+            == WatchMode::Stopwatch(StopwatchMode::Running)
+        {
+            state.now += Duration::from_millis(30);
+            assert!(model.update(&mut state).is_ok());
+        }
+
+        assert_eq!(state.current_stopwatch_time.seconds, 0);
+        assert_eq!(state.current_stopwatch_time.cents, 99);
+        assert_eq!(
+            state.stopwatch_elapsed.elapsed(state.now),
+            Duration::from_millis(990)
+        );
+
+        state.now += Duration::from_secs(1);
+        assert!(model.update(&mut state).is_ok());
+        assert_eq!(state.current_stopwatch_time.cents, 99);
+    }
 }
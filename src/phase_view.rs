@@ -0,0 +1,59 @@
+use crate::{
+    error::Error,
+    state::{State, WatchMode},
+    view::View,
+    display_view::DisplayNumber
+};
+
+use eframe::egui::{Ui, TextureId};
+
+/// Shows the current phase number of the interval timer.
+pub struct PhaseView {
+    depth: i32,
+
+    texture_id: TextureId,
+
+    number: DisplayNumber
+}
+
+impl PhaseView {
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(
+        depth: i32,
+        texture_id: TextureId,
+        number: DisplayNumber
+    ) -> Self {
+        Self {
+            depth: depth,
+
+            texture_id: texture_id,
+
+            number: number
+        }
+    }
+}
+
+impl View for PhaseView {
+    #[inline]
+    fn depth(&self) -> i32 {self.depth}
+
+    fn ready(&mut self, _ui: &Ui, _state: &mut State) -> Result<(), Error> {
+        // nothing to do.
+        Ok(())
+    }
+
+    fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
+        if !matches!(state.watch_mode, WatchMode::Timer(..))
+            || state.timer_phases.is_empty()
+        {
+            return Ok(());
+        }
+
+        self.number.show(ui, self.texture_id, (state.timer_phase + 1) as u32)
+    }
+
+    fn init(&mut self, _state: &mut State) -> Result<(), Error> {
+        // pass
+        Ok(())
+    }
+}
@@ -1,14 +1,24 @@
 use chobitlibs::{
-    chobit_sexpr::{ChobitSexpr, ChobitSexprBuf, Completed, Empty},
+    chobit_sexpr::{ChobitSexpr, ChobitSexprBuf, Completed, Empty, List},
     chobit_hash::fnv_1a_64
 };
 
 use crate::state::*;
 
-use std::cmp::PartialEq;
+use chrono::{DateTime, TimeZone, Utc};
+
+use std::{
+    cmp::PartialEq,
+    ffi::OsString,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::Duration
+};
 
 /*
 (
+    (version <version: u64>)
     (watch_mode <watch_mode: u64>)
     (stopwatch_time
         <hours: u32>
@@ -16,88 +26,279 @@ use std::cmp::PartialEq;
         <seconds: u32>
         <centiseconds: u32>
         <saved_time: f32>)
+    (stopwatch_running
+        <saved_at_seconds: u64>
+        <saved_at_nanoseconds: u32>)
+    (timer_time
+        <hours: u32>
+        <minutes: u32>
+        <seconds: u32>
+        <centiseconds: u32>
+        <saved_time: f32>)
+    (laps
+        (<split_hours: u32>
+         <split_minutes: u32>
+         <split_seconds: u32>
+         <split_centiseconds: u32>
+         <lap_hours: u32>
+         <lap_minutes: u32>
+         <lap_seconds: u32>
+         <lap_centiseconds: u32>)
+        ...)
+    (timer_phase <index: u64>)
+    (skin_override <watch_mode: u64> <skin_id: u64>)
+    ...
+    (alarm_ringing <index: u64>)
+    (alarm_snoozed
+        <index: u64>
+        <until_seconds: u64>
+        <until_nanoseconds: u32>)
 )
+
+`alarm_ringing` and `alarm_snoozed` are exclusive.
+
+Unknown entries are ignored so that newer versions can add entries.
+Unknown modes of `watch_mode` fall back to `clock`,
+and `skin_override` entries of unknown modes are ignored.
+
+History of versions.
+- version 0 : Unversioned. `(version ...)` entry doesn't exist.
+- version 1 : Adds `(version ...)` entry.
+- version 2 : Adds `(skin_override ...)` entries. One entry for each mode.
+- version 3 : Adds `(alarm_ringing ...)` and `(alarm_snoozed ...)` entries.
+- version 4 : Adds `(timer_phase ...)` entry.
+
+A file written by each version is kept in `tests/save_data/`.
  */
 
+pub const CURRENT_VERSION: u64 = 4;
+
+const SYMBOL_VERSION: u64 = fnv_1a_64(b"version");
 const SYMBOL_WATCH_MODE: u64 = fnv_1a_64(b"watch_mode");
 const SYMBOL_STOPWATCH: u64 = fnv_1a_64(b"stopwatch");
+const SYMBOL_TIMER: u64 = fnv_1a_64(b"timer");
 const SYMBOL_CLOCK: u64 = fnv_1a_64(b"clock");
 const SYMBOL_STOPWATCH_TIME: u64 = fnv_1a_64(b"stopwatch_time");
+const SYMBOL_STOPWATCH_RUNNING: u64 = fnv_1a_64(b"stopwatch_running");
+const SYMBOL_TIMER_TIME: u64 = fnv_1a_64(b"timer_time");
+const SYMBOL_LAPS: u64 = fnv_1a_64(b"laps");
+const SYMBOL_TIMER_PHASE: u64 = fnv_1a_64(b"timer_phase");
+const SYMBOL_SKIN_OVERRIDE: u64 = fnv_1a_64(b"skin_override");
+const SYMBOL_ALARM_RINGING: u64 = fnv_1a_64(b"alarm_ringing");
+const SYMBOL_ALARM_SNOOZED: u64 = fnv_1a_64(b"alarm_snoozed");
+
+/// `AlarmState` of save data.
+///
+/// `Instant` can't be saved, so the end of snooze is UTC time.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SavedAlarmState {
+    #[default]
+    Idle,
+    Ringing(usize),
+    Snoozed {index: usize, until: DateTime<Utc>}
+}
 
 #[derive(Debug, Clone)]
 pub struct SaveData {
     buf_0: Option<ChobitSexprBuf<Empty>>,
     buf_1: Option<ChobitSexprBuf<Empty>>,
     buf_2: Option<ChobitSexprBuf<Empty>>,
+    buf_3: Option<ChobitSexprBuf<Empty>>,
+
+    /// Version of the file the data was read from.
+    pub version: u64,
 
     pub watch_mode: WatchMode,
-    pub stopwatch_time: WatchTime,
-    pub saved_time: f32
+    pub stopwatch_elapsed: Duration,
+
+    /// UTC time of the save if the stopwatch was running.
+    pub stopwatch_running: Option<DateTime<Utc>>,
+
+    pub laps: Vec<LapTime>,
+    pub timer_remaining: Option<Duration>,
+
+    /// Index of the current phase of the interval timer.
+    pub timer_phase: usize,
+
+    pub skin_overrides: SkinOverrides,
+    pub alarm_state: SavedAlarmState
 }
 
 impl PartialEq for SaveData {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.watch_mode == other.watch_mode
-            && self.stopwatch_time == other.stopwatch_time
+            && WatchTime::from_duration(self.stopwatch_elapsed)
+                == WatchTime::from_duration(other.stopwatch_elapsed)
+            && self.stopwatch_running == other.stopwatch_running
+            && self.laps == other.laps
+            && self.timer_remaining.map(WatchTime::from_duration)
+                == other.timer_remaining.map(WatchTime::from_duration)
+            && self.timer_phase == other.timer_phase
+            && self.skin_overrides == other.skin_overrides
+            && self.alarm_state == other.alarm_state
     }
 }
 
-impl SaveData {
-    pub fn from_variables(
-        watch_mode: WatchMode,
-        stopwatch_time: WatchTime,
-        saved_time: f32
-    ) -> Self {
+/// Save data of the first launch.
+///
+/// `watch_mode` is `Clock`.
+/// Application uses `new()` with `default_mode` of the settings file.
+impl Default for SaveData {
+    fn default() -> Self {
         Self {
             buf_0: Some(ChobitSexprBuf::new()),
             buf_1: Some(ChobitSexprBuf::new()),
             buf_2: Some(ChobitSexprBuf::new()),
+            buf_3: Some(ChobitSexprBuf::new()),
+
+            version: CURRENT_VERSION,
+
+            watch_mode: WatchMode::Clock,
+            stopwatch_elapsed: Duration::ZERO,
+            stopwatch_running: None,
+            laps: Vec::<LapTime>::new(),
+            timer_remaining: None,
+            timer_phase: 0,
+            skin_overrides: SkinOverrides::default(),
+            alarm_state: SavedAlarmState::Idle
+        }
+    }
+}
 
+impl SaveData {
+    /// Save data of the first launch in `watch_mode`.
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(watch_mode: WatchMode) -> Self {
+        Self {
             watch_mode: watch_mode,
-            stopwatch_time: stopwatch_time,
-            saved_time: saved_time
+            ..Self::default()
         }
     }
 
+    #[allow(clippy::redundant_field_names)]
     pub fn from_bytes(
         bytes: &[u8]
     ) -> Option<Self> {
         let sexpr = ChobitSexpr::new(bytes);
 
+        let mut version: u64 = 0;
         let mut watch_mode: Option<WatchMode> = None;
-        let mut stopwatch_time: Option<(WatchTime, f32)> = None;
+        let mut stopwatch_elapsed: Option<Duration> = None;
+        let mut stopwatch_running: Option<Option<DateTime<Utc>>> = Some(None);
+        let mut laps: Option<Vec<LapTime>> = Some(Vec::<LapTime>::new());
+        let mut timer_remaining: Option<Option<Duration>> = Some(None);
+        let mut timer_phase: usize = 0;
+        let mut skin_overrides = SkinOverrides::default();
+        let mut alarm_state = SavedAlarmState::Idle;
 
         for elm in sexpr.iter() {
             let (car, cdr) = elm.car_cdr().ok()?;
 
             match car.read_u64().ok()? {
+                SYMBOL_VERSION => {
+                    version = cdr.car().ok()?.read_u64().ok()?;
+                },
+
                 SYMBOL_WATCH_MODE => {
-                    watch_mode = Self::load_watch_mode(cdr);
+                    // modes of newer versions fall back to the default mode.
+                    watch_mode = Some(
+                        Self::to_watch_mode(cdr.car().ok()?.read_u64().ok()?)
+                            .unwrap_or(WatchMode::Clock)
+                    );
                 },
 
                 SYMBOL_STOPWATCH_TIME => {
-                    stopwatch_time = Self::load_stopwatch_time(cdr);
+                    stopwatch_elapsed = Self::load_stopwatch_time(cdr);
+                },
+
+                SYMBOL_STOPWATCH_RUNNING => {
+                    stopwatch_running = Self::load_saved_at(cdr).map(Some);
+                },
+
+                SYMBOL_TIMER_TIME => {
+                    timer_remaining = Self::load_stopwatch_time(cdr).map(Some);
+                },
+
+                SYMBOL_LAPS => {
+                    laps = Self::load_laps(cdr);
+                },
+
+                SYMBOL_TIMER_PHASE => {
+                    timer_phase = Self::load_index(cdr)?;
+                },
+
+                SYMBOL_SKIN_OVERRIDE => {
+                    let (symbol, skin_id) = Self::load_skin_override(cdr)?;
+
+                    // ignores modes of newer versions.
+                    if let Some(watch_mode) = Self::to_watch_mode(symbol) {
+                        *skin_overrides.get_mut(watch_mode) = Some(skin_id);
+                    }
+                },
+
+                SYMBOL_ALARM_RINGING => {
+                    alarm_state = SavedAlarmState::Ringing(
+                        Self::load_index(cdr)?
+                    );
                 },
 
-                _ => {return None;}
+                SYMBOL_ALARM_SNOOZED => {
+                    alarm_state = SavedAlarmState::Snoozed {
+                        index: Self::load_index(cdr)?,
+                        until: Self::load_saved_at(cdr.cdr().ok()?)?
+                    };
+                },
+
+                _ => {}  // ignores unknown entries.
             }
         }
 
-        let (stopwatch_time, saved_time) = stopwatch_time?;
-
-        Some(Self {
+        let ret = Self {
             buf_0: Some(ChobitSexprBuf::new()),
             buf_1: Some(ChobitSexprBuf::new()),
             buf_2: Some(ChobitSexprBuf::new()),
+            buf_3: Some(ChobitSexprBuf::new()),
+
+            version: version,
 
             watch_mode: watch_mode?,
-            stopwatch_time: stopwatch_time,
-            saved_time: saved_time
-        })
+            stopwatch_elapsed: stopwatch_elapsed?,
+            stopwatch_running: stopwatch_running?,
+            laps: laps?,
+            timer_remaining: timer_remaining?,
+            timer_phase: timer_phase,
+            skin_overrides: skin_overrides,
+            alarm_state: alarm_state
+        };
+
+        Some(ret.migrate(version))
     }
 
-    fn load_stopwatch_time(cdr: &ChobitSexpr) -> Option<(WatchTime, f32)> {
+    // Converts data of old versions into the current version.
+    fn migrate(self, version: u64) -> Self {
+        match version {
+            // version 0 has the same entries except `version`.
+            // (missing entries have been filled with defaults.)
+            0 => self.migrate(1),
+
+            // skins haven't been chosen before version 2.
+            1 => self.migrate(2),
+
+            // alarms haven't rung before version 3.
+            2 => self.migrate(3),
+
+            // the timer has had only one phase before version 4.
+            3 => self.migrate(4),
+
+            _ => self
+        }
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    fn load_watch_time(
+        cdr: &ChobitSexpr
+    ) -> Option<(WatchTime, &ChobitSexpr)> {
         let (hours_sexpr, cdr) = cdr.car_cdr().ok()?;
         let hours = hours_sexpr.read_u32().ok()?;
 
@@ -110,9 +311,6 @@ impl SaveData {
         let (cents_sexpr, cdr) = cdr.car_cdr().ok()?;
         let cents = cents_sexpr.read_u32().ok()?;
 
-        let saved_time_sexpr = cdr.car().ok()?;
-        let saved_time = saved_time_sexpr.read_f32().ok()?;
-
         Some((
             WatchTime {
                 hours: hours,
@@ -120,17 +318,98 @@ impl SaveData {
                 seconds: seconds,
                 cents: cents
             },
-            saved_time
+            cdr
         ))
     }
 
-    fn load_watch_mode(cdr: &ChobitSexpr) -> Option<WatchMode> {
-        let mode_sexpr = cdr.car().ok()?;
+    #[allow(clippy::redundant_field_names)]
+    fn load_laps(cdr: &ChobitSexpr) -> Option<Vec<LapTime>> {
+        let mut ret = Vec::<LapTime>::new();
+
+        for elm in cdr.iter() {
+            let (split, elm) = Self::load_watch_time(elm)?;
+            let (lap, _) = Self::load_watch_time(elm)?;
+
+            ret.push(LapTime {
+                split: split,
+                lap: lap
+            });
+        }
+
+        Some(ret)
+    }
+
+    fn load_stopwatch_time(cdr: &ChobitSexpr) -> Option<Duration> {
+        let (stopwatch_time, cdr) = Self::load_watch_time(cdr)?;
 
-        match mode_sexpr.read_u64().ok()? {
+        let saved_time_sexpr = cdr.car().ok()?;
+        let saved_time = saved_time_sexpr.read_f32().ok()?;
+
+        Some(Self::to_duration(&stopwatch_time, saved_time))
+    }
+
+    fn load_saved_at(cdr: &ChobitSexpr) -> Option<DateTime<Utc>> {
+        let (seconds_sexpr, cdr) = cdr.car_cdr().ok()?;
+        let seconds = seconds_sexpr.read_u64().ok()?;
+
+        let nanoseconds_sexpr = cdr.car().ok()?;
+        let nanoseconds = nanoseconds_sexpr.read_u32().ok()?;
+
+        Utc.timestamp_opt(
+            i64::try_from(seconds).ok()?,
+            nanoseconds
+        ).single()
+    }
+
+    // `saved_time` is the fractional part of a second.
+    // (centiseconds included.)
+    // Centiseconds of `time` take precedence over the f32 precision,
+    // and `saved_time` adds only the time less than a centisecond.
+    fn to_duration(time: &WatchTime, saved_time: f32) -> Duration {
+        let duration = time.to_duration();
+
+        let remainder = (saved_time - ((time.cents as f32) / 100.0))
+            .clamp(0.0, 0.00999999);
+
+        if remainder.is_finite() {
+            duration + Duration::from_secs_f32(remainder)
+        } else {
+            duration
+        }
+    }
+
+    #[inline]
+    fn from_duration(duration: Duration) -> (WatchTime, f32) {
+        (
+            WatchTime::from_duration(duration),
+            (duration.subsec_nanos() as f32) / 1_000_000_000.0
+        )
+    }
+
+    // Returns the symbol of the mode and the skin ID.
+    fn load_skin_override(cdr: &ChobitSexpr) -> Option<(u64, u64)> {
+        let (mode_sexpr, cdr) = cdr.car_cdr().ok()?;
+        let symbol = mode_sexpr.read_u64().ok()?;
+
+        let skin_id_sexpr = cdr.car().ok()?;
+        let skin_id = skin_id_sexpr.read_u64().ok()?;
+
+        Some((symbol, skin_id))
+    }
+
+    fn load_index(cdr: &ChobitSexpr) -> Option<usize> {
+        let index_sexpr = cdr.car().ok()?;
+
+        usize::try_from(index_sexpr.read_u64().ok()?).ok()
+    }
+
+    fn to_watch_mode(symbol: u64) -> Option<WatchMode> {
+        match symbol {
             SYMBOL_STOPWATCH =>
                 Some(WatchMode::Stopwatch(StopwatchMode::Stopped)),
 
+            SYMBOL_TIMER => Some(WatchMode::Timer(TimerMode::Stopped)),
+
             SYMBOL_CLOCK => Some(WatchMode::Clock),
 
             _ => None
@@ -147,9 +426,21 @@ impl SaveData {
         let sexpr_buf_2 = self.buf_2.take().expect(
             "Error at SaveData::write_bytes() #3"
         );
+        let sexpr_buf_3 = self.buf_3.take().expect(
+            "Error at SaveData::write_bytes() #4"
+        );
 
         let sexpr_buf_0 = sexpr_buf_0.build_list();
 
+        let (sexpr_buf_1, sexpr_buf_2) = Self::build_version_sexpr(
+            sexpr_buf_1,
+            sexpr_buf_2
+        );
+        let sexpr_buf_0 = sexpr_buf_0.push_item(&sexpr_buf_1);
+
+        let sexpr_buf_1 = sexpr_buf_1.clear();
+        let sexpr_buf_2 = sexpr_buf_2.clear();
+
         let (sexpr_buf_1, sexpr_buf_2) = Self::build_watch_mode_sexpr(
             &self.watch_mode,
             sexpr_buf_1,
@@ -160,13 +451,123 @@ impl SaveData {
         let sexpr_buf_1 = sexpr_buf_1.clear();
         let sexpr_buf_2 = sexpr_buf_2.clear();
 
+        let (stopwatch_time, saved_time) =
+            Self::from_duration(self.stopwatch_elapsed);
+
         let (sexpr_buf_1, sexpr_buf_2) = Self::build_stopwatch_time_sexpr(
-            &self.stopwatch_time,
-            self.saved_time,
+            SYMBOL_STOPWATCH_TIME,
+            &stopwatch_time,
+            saved_time,
             sexpr_buf_1,
             sexpr_buf_2
         );
-        let sexpr_buf_0 = sexpr_buf_0.push_item(&sexpr_buf_1);
+        let mut sexpr_buf_0 = sexpr_buf_0.push_item(&sexpr_buf_1);
+
+        let mut sexpr_buf_1 = sexpr_buf_1.clear();
+        let mut sexpr_buf_2 = sexpr_buf_2.clear();
+
+        if let Some(saved_at) = self.stopwatch_running.as_ref() {
+            let (buf_1, buf_2) = Self::build_stopwatch_running_sexpr(
+                saved_at,
+                sexpr_buf_1,
+                sexpr_buf_2
+            );
+            sexpr_buf_0 = sexpr_buf_0.push_item(&buf_1);
+
+            sexpr_buf_1 = buf_1.clear();
+            sexpr_buf_2 = buf_2.clear();
+        }
+
+        if let Some(timer_remaining) = self.timer_remaining {
+            let (timer_time, timer_saved_time) =
+                Self::from_duration(timer_remaining);
+
+            let (buf_1, buf_2) = Self::build_stopwatch_time_sexpr(
+                SYMBOL_TIMER_TIME,
+                &timer_time,
+                timer_saved_time,
+                sexpr_buf_1,
+                sexpr_buf_2
+            );
+            sexpr_buf_0 = sexpr_buf_0.push_item(&buf_1);
+
+            sexpr_buf_1 = buf_1.clear();
+            sexpr_buf_2 = buf_2.clear();
+        }
+
+        if self.timer_phase != 0 {
+            let (buf_1, buf_2) = Self::build_timer_phase_sexpr(
+                self.timer_phase,
+                sexpr_buf_1,
+                sexpr_buf_2
+            );
+            sexpr_buf_0 = sexpr_buf_0.push_item(&buf_1);
+
+            sexpr_buf_1 = buf_1.clear();
+            sexpr_buf_2 = buf_2.clear();
+        }
+
+        let (sexpr_buf_1, sexpr_buf_2, sexpr_buf_3) = Self::build_laps_sexpr(
+            &self.laps,
+            sexpr_buf_1,
+            sexpr_buf_2,
+            sexpr_buf_3
+        );
+        let mut sexpr_buf_0 = sexpr_buf_0.push_item(&sexpr_buf_1);
+
+        let mut sexpr_buf_1 = sexpr_buf_1.clear();
+        let mut sexpr_buf_2 = sexpr_buf_2.clear();
+
+        let skin_overrides = [
+            (
+                WatchMode::Stopwatch(StopwatchMode::Stopped),
+                self.skin_overrides.stopwatch
+            ),
+            (
+                WatchMode::Timer(TimerMode::Stopped),
+                self.skin_overrides.timer
+            ),
+            (WatchMode::Clock, self.skin_overrides.clock)
+        ];
+
+        for (watch_mode, skin_id) in skin_overrides {
+            if let Some(skin_id) = skin_id {
+                let (buf_1, buf_2) = Self::build_skin_override_sexpr(
+                    &watch_mode,
+                    skin_id,
+                    sexpr_buf_1,
+                    sexpr_buf_2
+                );
+                sexpr_buf_0 = sexpr_buf_0.push_item(&buf_1);
+
+                sexpr_buf_1 = buf_1.clear();
+                sexpr_buf_2 = buf_2.clear();
+            }
+        }
+
+        let alarm_state = match &self.alarm_state {
+            SavedAlarmState::Idle => None,
+
+            SavedAlarmState::Ringing(index) =>
+                Some((SYMBOL_ALARM_RINGING, *index, None)),
+
+            SavedAlarmState::Snoozed {index, until} =>
+                Some((SYMBOL_ALARM_SNOOZED, *index, Some(until)))
+        };
+
+        if let Some((symbol, index, until)) = alarm_state {
+            let (buf_1, buf_2) = Self::build_alarm_state_sexpr(
+                symbol,
+                index,
+                until,
+                sexpr_buf_1,
+                sexpr_buf_2
+            );
+            sexpr_buf_0 = sexpr_buf_0.push_item(&buf_1);
+
+            sexpr_buf_1 = buf_1.clear();
+            sexpr_buf_2 = buf_2.clear();
+        }
 
         let sexpr_buf_0 = sexpr_buf_0.finish();
 
@@ -174,8 +575,73 @@ impl SaveData {
         buf.extend_from_slice(sexpr_buf_0.as_sexpr().as_bytes());
 
         self.buf_0 = Some(sexpr_buf_0.clear());
-        self.buf_1 = Some(sexpr_buf_1.clear());
-        self.buf_2 = Some(sexpr_buf_2.clear());
+        self.buf_1 = Some(sexpr_buf_1);
+        self.buf_2 = Some(sexpr_buf_2);
+        self.buf_3 = Some(sexpr_buf_3);
+    }
+
+    /// Writes save data to a temporary file and renames it to `path`.
+    /// The previous file is kept as the backup file.
+    pub fn write_file(
+        &mut self,
+        path: &Path,
+        buf: &mut Vec<u8>
+    ) -> io::Result<()> {
+        self.write_bytes(buf);
+
+        let temp_path = Self::temp_path(path);
+
+        {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&temp_path)?;
+
+            file.write_all(buf)?;
+            file.sync_all()?;
+        }
+
+        if path.is_file() {
+            fs::rename(path, Self::backup_path(path))?;
+        }
+
+        fs::rename(&temp_path, path)
+    }
+
+    #[inline]
+    pub fn backup_path(path: &Path) -> PathBuf {
+        Self::add_extension(path, ".bak")
+    }
+
+    #[inline]
+    fn temp_path(path: &Path) -> PathBuf {
+        Self::add_extension(path, ".tmp")
+    }
+
+    #[inline]
+    fn add_extension(path: &Path, extension: &str) -> PathBuf {
+        let mut ret = OsString::from(path.as_os_str());
+        ret.push(extension);
+
+        PathBuf::from(ret)
+    }
+
+    fn build_version_sexpr(
+        buf_1: ChobitSexprBuf<Empty>,
+        buf_2: ChobitSexprBuf<Empty>
+    ) -> (ChobitSexprBuf<Completed>, ChobitSexprBuf<Completed>) {
+        let buf_1 = buf_1.build_list();
+
+        let buf_2 = buf_2.push_u64(SYMBOL_VERSION);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u64(CURRENT_VERSION);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_1 = buf_1.finish();
+
+        (buf_1, buf_2)
     }
 
     fn build_watch_mode_sexpr(
@@ -190,6 +656,7 @@ impl SaveData {
 
         let buf_2 = buf_2.clear().push_u64(match watch_mode {
             WatchMode::Stopwatch(..) => SYMBOL_STOPWATCH,
+            WatchMode::Timer(..) => SYMBOL_TIMER,
             WatchMode::Clock => SYMBOL_CLOCK,
         });
         let buf_1 = buf_1.push_item(&buf_2);
@@ -199,7 +666,83 @@ impl SaveData {
         (buf_1, buf_2)
     }
 
+    fn build_skin_override_sexpr(
+        watch_mode: &WatchMode,
+        skin_id: u64,
+        buf_1: ChobitSexprBuf<Empty>,
+        buf_2: ChobitSexprBuf<Empty>
+    ) -> (ChobitSexprBuf<Completed>, ChobitSexprBuf<Completed>) {
+        let buf_1 = buf_1.build_list();
+
+        let buf_2 = buf_2.push_u64(SYMBOL_SKIN_OVERRIDE);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u64(match watch_mode {
+            WatchMode::Stopwatch(..) => SYMBOL_STOPWATCH,
+            WatchMode::Timer(..) => SYMBOL_TIMER,
+            WatchMode::Clock => SYMBOL_CLOCK,
+        });
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u64(skin_id);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_1 = buf_1.finish();
+
+        (buf_1, buf_2)
+    }
+
+    fn build_timer_phase_sexpr(
+        timer_phase: usize,
+        buf_1: ChobitSexprBuf<Empty>,
+        buf_2: ChobitSexprBuf<Empty>
+    ) -> (ChobitSexprBuf<Completed>, ChobitSexprBuf<Completed>) {
+        let buf_1 = buf_1.build_list();
+
+        let buf_2 = buf_2.push_u64(SYMBOL_TIMER_PHASE);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u64(timer_phase as u64);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_1 = buf_1.finish();
+
+        (buf_1, buf_2)
+    }
+
+    // `until` is only for snoozed alarms.
+    fn build_alarm_state_sexpr(
+        symbol: u64,
+        index: usize,
+        until: Option<&DateTime<Utc>>,
+        buf_1: ChobitSexprBuf<Empty>,
+        buf_2: ChobitSexprBuf<Empty>
+    ) -> (ChobitSexprBuf<Completed>, ChobitSexprBuf<Completed>) {
+        let buf_1 = buf_1.build_list();
+
+        let buf_2 = buf_2.push_u64(symbol);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u64(index as u64);
+        let mut buf_1 = buf_1.push_item(&buf_2);
+
+        let mut buf_2 = buf_2;
+
+        if let Some(until) = until {
+            buf_2 = buf_2.clear().push_u64(until.timestamp().max(0) as u64);
+            buf_1 = buf_1.push_item(&buf_2);
+
+            buf_2 = buf_2.clear().push_u32(until.timestamp_subsec_nanos());
+            buf_1 = buf_1.push_item(&buf_2);
+        }
+
+        let buf_1 = buf_1.finish();
+
+        (buf_1, buf_2)
+    }
+
     fn build_stopwatch_time_sexpr(
+        symbol: u64,
         stopwatch_time: &WatchTime,
         saved_time: f32,
         buf_1: ChobitSexprBuf<Empty>,
@@ -207,28 +750,104 @@ impl SaveData {
     ) -> (ChobitSexprBuf<Completed>, ChobitSexprBuf<Completed>) {
         let buf_1 = buf_1.build_list();
 
-        let buf_2 = buf_2.push_u64(SYMBOL_STOPWATCH_TIME);
+        let buf_2 = buf_2.push_u64(symbol);
         let buf_1 = buf_1.push_item(&buf_2);
 
-        let buf_2 = buf_2.clear().push_u32(stopwatch_time.hours);
-        let buf_1 = buf_1.push_item(&buf_2);
+        let (buf_1, buf_2) =
+            Self::push_watch_time_items(stopwatch_time, buf_1, buf_2);
 
-        let buf_2 = buf_2.clear().push_u32(stopwatch_time.minutes);
+        let buf_2 = buf_2.clear().push_f32(saved_time);
         let buf_1 = buf_1.push_item(&buf_2);
 
-        let buf_2 = buf_2.clear().push_u32(stopwatch_time.seconds);
+        let buf_1 = buf_1.finish();
+
+        (buf_1, buf_2)
+    }
+
+    fn build_stopwatch_running_sexpr(
+        saved_at: &DateTime<Utc>,
+        buf_1: ChobitSexprBuf<Empty>,
+        buf_2: ChobitSexprBuf<Empty>
+    ) -> (ChobitSexprBuf<Completed>, ChobitSexprBuf<Completed>) {
+        let buf_1 = buf_1.build_list();
+
+        let buf_2 = buf_2.push_u64(SYMBOL_STOPWATCH_RUNNING);
         let buf_1 = buf_1.push_item(&buf_2);
 
-        let buf_2 = buf_2.clear().push_u32(stopwatch_time.cents);
+        let buf_2 = buf_2.clear().push_u64(saved_at.timestamp().max(0) as u64);
         let buf_1 = buf_1.push_item(&buf_2);
 
-        let buf_2 = buf_2.clear().push_f32(saved_time);
+        let buf_2 = buf_2.clear().push_u32(saved_at.timestamp_subsec_nanos());
         let buf_1 = buf_1.push_item(&buf_2);
 
         let buf_1 = buf_1.finish();
 
         (buf_1, buf_2)
     }
+
+    fn push_watch_time_items(
+        watch_time: &WatchTime,
+        buf_1: ChobitSexprBuf<List>,
+        buf_2: ChobitSexprBuf<Completed>
+    ) -> (ChobitSexprBuf<List>, ChobitSexprBuf<Completed>) {
+        let buf_2 = buf_2.clear().push_u32(watch_time.hours);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u32(watch_time.minutes);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u32(watch_time.seconds);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        let buf_2 = buf_2.clear().push_u32(watch_time.cents);
+        let buf_1 = buf_1.push_item(&buf_2);
+
+        (buf_1, buf_2)
+    }
+
+    fn build_laps_sexpr(
+        laps: &[LapTime],
+        buf_1: ChobitSexprBuf<Empty>,
+        buf_2: ChobitSexprBuf<Empty>,
+        buf_3: ChobitSexprBuf<Empty>
+    ) -> (
+        ChobitSexprBuf<Completed>,
+        ChobitSexprBuf<Completed>,
+        ChobitSexprBuf<Empty>
+    ) {
+        let buf_1 = buf_1.build_list();
+
+        let buf_2 = buf_2.push_u64(SYMBOL_LAPS);
+        let mut buf_1 = buf_1.push_item(&buf_2);
+
+        let mut buf_2 = buf_2;
+        let mut buf_3 = buf_3;
+
+        for lap_time in laps {
+            let buf_3_list = buf_3.build_list();
+
+            let (buf_3_list, buf_2_tmp) = Self::push_watch_time_items(
+                &lap_time.split,
+                buf_3_list,
+                buf_2
+            );
+            let (buf_3_list, buf_2_tmp) = Self::push_watch_time_items(
+                &lap_time.lap,
+                buf_3_list,
+                buf_2_tmp
+            );
+
+            let buf_3_completed = buf_3_list.finish();
+            buf_1 = buf_1.push_item(&buf_3_completed);
+
+            buf_2 = buf_2_tmp;
+            buf_3 = buf_3_completed.clear();
+        }
+
+        let buf_1 = buf_1.finish();
+
+        (buf_1, buf_2, buf_3)
+    }
 }
 
 #[cfg(test)]
@@ -236,20 +855,164 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::redundant_field_names)]
+    fn fixtures_test() {
+        let lap_time = WatchTime {hours: 0, minutes: 1, seconds: 2, cents: 3};
+        let laps = vec![LapTime {split: lap_time.clone(), lap: lap_time}];
+
+        let skin_overrides = SkinOverrides {
+            stopwatch: Some(fnv_1a_64(b"skin_1")),
+            timer: None,
+            clock: Some(fnv_1a_64(b"skin_2"))
+        };
+
+        let alarm_state = SavedAlarmState::Snoozed {
+            index: 1,
+            until: Utc.timestamp_opt(1_700_000_300, 123_456_789).unwrap()
+        };
+
+        let version_1 = SaveData {
+            watch_mode: WatchMode::Stopwatch(StopwatchMode::Stopped),
+            stopwatch_elapsed: Duration::from_millis(3_723_456),
+            stopwatch_running: Some(
+                Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+            ),
+            laps: laps,
+            timer_remaining: Some(Duration::from_secs(300)),
+            ..SaveData::default()
+        };
+
+        // files written by each version and what they have.
+        let fixtures: [(&[u8], SaveData); 5] = [
+            (
+                include_bytes!("../tests/save_data/version_0.data"),
+                SaveData {
+                    watch_mode: WatchMode::Stopwatch(StopwatchMode::Stopped),
+                    stopwatch_elapsed: Duration::from_millis(3_723_450),
+                    ..SaveData::default()
+                }
+            ),
+            (
+                include_bytes!("../tests/save_data/version_1.data"),
+                version_1.clone()
+            ),
+            (
+                include_bytes!("../tests/save_data/version_2.data"),
+                SaveData {
+                    skin_overrides: skin_overrides,
+                    ..version_1.clone()
+                }
+            ),
+            (
+                include_bytes!("../tests/save_data/version_3.data"),
+                SaveData {
+                    skin_overrides: skin_overrides,
+                    alarm_state: alarm_state,
+                    ..version_1.clone()
+                }
+            ),
+            (
+                include_bytes!("../tests/save_data/version_4.data"),
+                SaveData {
+                    timer_phase: 2,
+                    skin_overrides: skin_overrides,
+                    alarm_state: alarm_state,
+                    ..version_1
+                }
+            )
+        ];
+
+        for (bytes, expected) in fixtures {
+            assert_eq!(SaveData::from_bytes(bytes).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::redundant_field_names)]
     fn save_data_test() {
-        let stopwatch_time = WatchTime {
+        let stopwatch_elapsed = WatchTime {
             hours: 10,
             minutes: 20,
             seconds: 30,
             cents: 40
+        }.to_duration() + Duration::from_micros(3);
+
+        let mut save_data_1 = SaveData {
+            stopwatch_elapsed: stopwatch_elapsed,
+            laps: vec![
+                LapTime {
+                    split: WatchTime {
+                        hours: 0,
+                        minutes: 1,
+                        seconds: 2,
+                        cents: 3
+                    },
+                    lap: WatchTime {
+                        hours: 0,
+                        minutes: 1,
+                        seconds: 2,
+                        cents: 3
+                    }
+                },
+                LapTime {
+                    split: WatchTime {
+                        hours: 0,
+                        minutes: 4,
+                        seconds: 5,
+                        cents: 6
+                    },
+                    lap: WatchTime {
+                        hours: 0,
+                        minutes: 3,
+                        seconds: 3,
+                        cents: 3
+                    }
+                }
+            ],
+            timer_remaining: Some(Duration::from_millis(5 * 60 * 1000 + 2)),
+            ..SaveData::default()
+        };
+
+        let mut buf = Vec::<u8>::new();
+
+        save_data_1.write_bytes(&mut buf);
+
+        let save_data_2 = SaveData::from_bytes(&buf).unwrap();
+
+        assert_eq!(save_data_2, save_data_1);
+    }
+
+    #[test]
+    fn save_data_test_2() {
+        let mut save_data_1 = SaveData {
+            watch_mode: WatchMode::Timer(TimerMode::Running),
+            ..SaveData::default()
         };
-        let saved_time: f32 = 0.003;
 
-        let mut save_data_1 = SaveData::from_variables(
-            WatchMode::Clock,
-            stopwatch_time,
-            saved_time
+        let mut buf = Vec::<u8>::new();
+
+        save_data_1.write_bytes(&mut buf);
+
+        let save_data_2 = SaveData::from_bytes(&buf).unwrap();
+
+        assert_eq!(
+            save_data_2.watch_mode,
+            WatchMode::Timer(TimerMode::Stopped)
         );
+        assert_eq!(save_data_2.timer_remaining, None);
+        assert!(save_data_2.laps.is_empty());
+    }
+
+    #[test]
+    fn stopwatch_running_test() {
+        let saved_at = Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap();
+
+        let mut save_data_1 = SaveData {
+            watch_mode: WatchMode::Stopwatch(StopwatchMode::Stopped),
+            stopwatch_elapsed: Duration::from_millis(12_345),
+            stopwatch_running: Some(saved_at),
+            ..SaveData::default()
+        };
 
         let mut buf = Vec::<u8>::new();
 
@@ -258,5 +1021,360 @@ mod tests {
         let save_data_2 = SaveData::from_bytes(&buf).unwrap();
 
         assert_eq!(save_data_2, save_data_1);
+        assert_eq!(save_data_2.stopwatch_running, Some(saved_at));
+    }
+
+    #[test]
+    fn write_file_test() {
+        use crate::settings_loader::SettingsLoader;
+
+        let dir = std::env::temp_dir().join(format!(
+            "anipwatch_write_file_test_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("save.data");
+        let mut buf = Vec::<u8>::new();
+
+        let mut save_data_1 = SaveData {
+            stopwatch_elapsed: Duration::from_secs(1),
+            ..SaveData::default()
+        };
+        let mut save_data_2 = SaveData {
+            watch_mode: WatchMode::Stopwatch(StopwatchMode::Stopped),
+            stopwatch_elapsed: Duration::from_secs(2),
+            laps: vec![LapTime::default()],
+            ..SaveData::default()
+        };
+
+        save_data_2.write_file(&path, &mut buf).unwrap();
+        save_data_1.write_file(&path, &mut buf).unwrap();
+
+        // no trailing garbage of the longer previous payload.
+        assert_eq!(fs::read(&path).unwrap(), buf);
+        assert!(!SaveData::temp_path(&path).exists());
+
+        assert_eq!(
+            SettingsLoader::load_save_data_file(&dir, &"save.data").unwrap(),
+            save_data_1
+        );
+
+        fs::write(&path, b"broken").unwrap();
+
+        assert_eq!(
+            SettingsLoader::load_save_data_file(&dir, &"save.data").unwrap(),
+            save_data_2
+        );
+
+        fs::remove_file(SaveData::backup_path(&path)).unwrap();
+
+        assert!(
+            SettingsLoader::load_save_data_file(&dir, &"save.data").is_err()
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    type Entry = ChobitSexprBuf<Completed>;
+
+    fn entry(
+        builder: impl FnOnce(
+            ChobitSexprBuf<Empty>,
+            ChobitSexprBuf<Empty>
+        ) -> (Entry, Entry)
+    ) -> Entry {
+        builder(ChobitSexprBuf::new(), ChobitSexprBuf::new()).0
+    }
+
+    fn u64_entry(symbol: &[u8], values: &[u64]) -> Entry {
+        let buf_1 = ChobitSexprBuf::new().build_list();
+
+        let buf_2 = ChobitSexprBuf::new().push_u64(fnv_1a_64(symbol));
+        let mut buf_1 = buf_1.push_item(&buf_2);
+
+        for value in values {
+            buf_1 = buf_1.push_item(&ChobitSexprBuf::new().push_u64(*value));
+        }
+
+        buf_1.finish()
+    }
+
+    fn build_bytes(entries: &[Entry]) -> Vec<u8> {
+        let mut buf = ChobitSexprBuf::new().build_list();
+
+        for entry in entries {
+            buf = buf.push_item(entry);
+        }
+
+        buf.finish().as_sexpr().as_bytes().to_vec()
+    }
+
+    fn stopwatch_time_entry(
+        symbol: u64,
+        time: &WatchTime,
+        saved_time: f32
+    ) -> Entry {
+        entry(|buf_1, buf_2| SaveData::build_stopwatch_time_sexpr(
+            symbol,
+            time,
+            saved_time,
+            buf_1,
+            buf_2
+        ))
+    }
+
+    const TIME: WatchTime = WatchTime {
+        hours: 1,
+        minutes: 2,
+        seconds: 3,
+        cents: 45
+    };
+
+    #[test]
+    fn version_0_test() {
+        // written by the first release.
+        let bytes = build_bytes(&[
+            entry(|buf_1, buf_2| SaveData::build_watch_mode_sexpr(
+                &WatchMode::Stopwatch(StopwatchMode::Stopped),
+                buf_1,
+                buf_2
+            )),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.456)
+        ]);
+
+        let save_data = SaveData::from_bytes(&bytes).unwrap();
+
+        assert_eq!(
+            save_data.watch_mode,
+            WatchMode::Stopwatch(StopwatchMode::Stopped)
+        );
+        assert_eq!(WatchTime::from_duration(save_data.stopwatch_elapsed), TIME);
+        assert!(
+            save_data.stopwatch_elapsed > TIME.to_duration()
+                + Duration::from_millis(5)
+        );
+        assert_eq!(save_data.stopwatch_running, None);
+        assert!(save_data.laps.is_empty());
+        assert_eq!(save_data.timer_remaining, None);
+
+        // round trip into the current version.
+        let mut save_data_1 = save_data;
+        let mut buf = Vec::<u8>::new();
+        save_data_1.write_bytes(&mut buf);
+
+        let save_data_2 = SaveData::from_bytes(&buf).unwrap();
+        assert_eq!(save_data_2, save_data_1);
+    }
+
+    #[test]
+    fn version_0_test_2() {
+        // unversioned data with laps and timer.
+        let bytes = build_bytes(&[
+            entry(|buf_1, buf_2| SaveData::build_watch_mode_sexpr(
+                &WatchMode::Timer(TimerMode::Running),
+                buf_1,
+                buf_2
+            )),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.456),
+            stopwatch_time_entry(SYMBOL_TIMER_TIME, &TIME, 0.456),
+            entry(|buf_1, buf_2| {
+                let (buf_1, buf_2, _) = SaveData::build_laps_sexpr(
+                    &[LapTime {split: TIME, lap: TIME}],
+                    buf_1,
+                    buf_2,
+                    ChobitSexprBuf::new()
+                );
+
+                (buf_1, buf_2)
+            })
+        ]);
+
+        let mut save_data_1 = SaveData::from_bytes(&bytes).unwrap();
+
+        assert_eq!(
+            save_data_1.watch_mode,
+            WatchMode::Timer(TimerMode::Stopped)
+        );
+        assert_eq!(
+            save_data_1.timer_remaining.map(WatchTime::from_duration),
+            Some(TIME)
+        );
+        assert_eq!(save_data_1.laps, vec![LapTime {split: TIME, lap: TIME}]);
+
+        let mut buf = Vec::<u8>::new();
+        save_data_1.write_bytes(&mut buf);
+
+        let save_data_2 = SaveData::from_bytes(&buf).unwrap();
+        assert_eq!(save_data_2, save_data_1);
+    }
+
+    #[test]
+    fn version_1_test() {
+        let bytes = build_bytes(&[
+            u64_entry(b"version", &[1]),
+            u64_entry(b"watch_mode", &[SYMBOL_CLOCK]),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.0)
+        ]);
+
+        let save_data = SaveData::from_bytes(&bytes).unwrap();
+
+        assert_eq!(save_data.watch_mode, WatchMode::Clock);
+        assert_eq!(save_data.skin_overrides, SkinOverrides::default());
+    }
+
+    #[test]
+    fn version_2_test() {
+        let mut save_data_1 = SaveData {
+            watch_mode: WatchMode::Stopwatch(StopwatchMode::Stopped),
+            stopwatch_elapsed: TIME.to_duration(),
+            stopwatch_running: Some(
+                Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+            ),
+            laps: vec![LapTime {split: TIME, lap: TIME}],
+            timer_remaining: Some(TIME.to_duration()),
+            skin_overrides: SkinOverrides {
+                stopwatch: Some(fnv_1a_64(b"skin_1")),
+                timer: None,
+                clock: Some(fnv_1a_64(b"skin_2"))
+            },
+            ..SaveData::default()
+        };
+
+        let mut buf = Vec::<u8>::new();
+        save_data_1.write_bytes(&mut buf);
+
+        // the first entry is the version.
+        let sexpr = ChobitSexpr::new(&buf);
+        let (car, cdr) = sexpr.car().unwrap().car_cdr().unwrap();
+        assert_eq!(car.read_u64().unwrap(), SYMBOL_VERSION);
+        assert_eq!(cdr.car().unwrap().read_u64().unwrap(), CURRENT_VERSION);
+
+        let save_data_2 = SaveData::from_bytes(&buf).unwrap();
+        assert_eq!(save_data_2, save_data_1);
+    }
+
+    #[test]
+    fn version_2_test_2() {
+        let bytes = build_bytes(&[
+            u64_entry(b"version", &[2]),
+            u64_entry(b"watch_mode", &[SYMBOL_CLOCK]),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.0),
+            u64_entry(b"skin_override", &[SYMBOL_CLOCK, fnv_1a_64(b"skin_1")])
+        ]);
+
+        let save_data = SaveData::from_bytes(&bytes).unwrap();
+
+        assert_eq!(save_data.skin_overrides.clock, Some(fnv_1a_64(b"skin_1")));
+        assert_eq!(save_data.alarm_state, SavedAlarmState::Idle);
+    }
+
+    #[test]
+    #[allow(clippy::redundant_field_names)]
+    fn version_3_test() {
+        let alarm_states = [
+            SavedAlarmState::Idle,
+            SavedAlarmState::Ringing(2),
+            SavedAlarmState::Snoozed {
+                index: 1,
+                until: Utc.timestamp_opt(1_700_000_300, 123_456_789).unwrap()
+            }
+        ];
+
+        for alarm_state in alarm_states {
+            let mut save_data_1 = SaveData {
+                stopwatch_elapsed: TIME.to_duration(),
+                alarm_state: alarm_state,
+                ..SaveData::default()
+            };
+
+            let mut buf = Vec::<u8>::new();
+            save_data_1.write_bytes(&mut buf);
+
+            let save_data_2 = SaveData::from_bytes(&buf).unwrap();
+            assert_eq!(save_data_2, save_data_1);
+            assert_eq!(save_data_2.alarm_state, alarm_state);
+        }
+    }
+
+    #[test]
+    fn version_4_test() {
+        let bytes = build_bytes(&[
+            u64_entry(b"version", &[3]),
+            u64_entry(b"watch_mode", &[SYMBOL_TIMER]),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.0),
+            stopwatch_time_entry(SYMBOL_TIMER_TIME, &TIME, 0.0)
+        ]);
+
+        let save_data = SaveData::from_bytes(&bytes).unwrap();
+        assert_eq!(save_data.timer_phase, 0);
+
+        let mut save_data_1 = SaveData {
+            watch_mode: WatchMode::Timer(TimerMode::Stopped),
+            timer_remaining: Some(TIME.to_duration()),
+            timer_phase: 3,
+            ..SaveData::default()
+        };
+
+        let mut buf = Vec::<u8>::new();
+        save_data_1.write_bytes(&mut buf);
+
+        let save_data_2 = SaveData::from_bytes(&buf).unwrap();
+        assert_eq!(save_data_2, save_data_1);
+        assert_eq!(save_data_2.timer_phase, 3);
+    }
+
+    #[test]
+    fn unknown_entry_test() {
+        // data written by a newer version.
+        let bytes = build_bytes(&[
+            u64_entry(b"version", &[CURRENT_VERSION + 1]),
+            u64_entry(b"unknown_entry_1", &[]),
+            entry(|buf_1, buf_2| SaveData::build_watch_mode_sexpr(
+                &WatchMode::Clock,
+                buf_1,
+                buf_2
+            )),
+            u64_entry(b"unknown_entry_2", &[1, 2, 3]),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.45)
+        ]);
+
+        let save_data = SaveData::from_bytes(&bytes).unwrap();
+
+        assert_eq!(save_data.version, CURRENT_VERSION + 1);
+        assert_eq!(save_data.watch_mode, WatchMode::Clock);
+        assert_eq!(WatchTime::from_duration(save_data.stopwatch_elapsed), TIME);
+    }
+
+    #[test]
+    fn unknown_mode_test() {
+        // modes added by a newer version.
+        let bytes = build_bytes(&[
+            u64_entry(b"version", &[CURRENT_VERSION + 1]),
+            u64_entry(b"watch_mode", &[fnv_1a_64(b"unknown_mode")]),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.0),
+            u64_entry(b"skin_override", &[SYMBOL_TIMER, 1]),
+            u64_entry(b"skin_override", &[fnv_1a_64(b"unknown_mode"), 2]),
+            u64_entry(b"skin_override", &[SYMBOL_CLOCK, 3])
+        ]);
+
+        let save_data = SaveData::from_bytes(&bytes).unwrap();
+
+        assert_eq!(save_data.watch_mode, WatchMode::Clock);
+        assert_eq!(save_data.skin_overrides, SkinOverrides {
+            stopwatch: None,
+            timer: Some(1),
+            clock: Some(3)
+        });
+
+        // broken entries are still rejected.
+        let bytes = build_bytes(&[
+            u64_entry(b"watch_mode", &[SYMBOL_CLOCK]),
+            stopwatch_time_entry(SYMBOL_STOPWATCH_TIME, &TIME, 0.0),
+            u64_entry(b"skin_override", &[SYMBOL_CLOCK])
+        ]);
+
+        assert!(SaveData::from_bytes(&bytes).is_none());
     }
 }
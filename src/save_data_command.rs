@@ -0,0 +1,575 @@
+use crate::{
+    error::Error,
+    state::*,
+    settings::{Settings, TimeElement},
+    settings_loader::SettingsLoader,
+    save_data::{SaveData, SavedAlarmState},
+    application::{Application, ApplicationError}
+};
+
+use chobitlibs::chobit_hash::fnv_1a_64;
+
+use getopts::{Options, Matches};
+
+use serde::Serialize;
+
+use std::{
+    path::Path,
+    process::ExitCode,
+    time::Duration
+};
+
+pub const SUBCOMMAND: &str = "save-data";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SaveDataYaml {
+    watch_mode: &'static str,
+    stopwatch_time: String,
+    stopwatch_running: Option<String>,
+    timer_time: Option<String>,
+    timer_phase: usize,
+    laps: Vec<LapYaml>,
+    skin_overrides: SkinOverridesYaml,
+    alarm_ringing: Option<usize>,
+    alarm_snoozed: Option<AlarmSnoozedYaml>
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct LapYaml {
+    split: String,
+    lap: String
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SkinOverridesYaml {
+    stopwatch: Option<SkinYaml>,
+    timer: Option<SkinYaml>,
+    clock: Option<SkinYaml>
+}
+
+// skins are saved as fnv_1a_64 hashes of their names.
+// `name` is `None` without settings file or if no skin has the hash.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SkinYaml {
+    name: Option<String>,
+    hash: u64
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct AlarmSnoozedYaml {
+    index: usize,
+    until: String
+}
+
+impl SaveDataYaml {
+    #[allow(clippy::redundant_field_names)]
+    fn new(save_data: &SaveData, settings: Option<&Settings>) -> Self {
+        let skin_yaml = |skin_id: u64| SkinYaml {
+            name: settings.and_then(
+                |settings| settings.skins.iter().find(
+                    |skin| fnv_1a_64(skin.name.as_bytes()) == skin_id
+                )
+            ).map(|skin| skin.name.clone()),
+
+            hash: skin_id
+        };
+
+        Self {
+            watch_mode: match save_data.watch_mode {
+                WatchMode::Stopwatch(..) => "stopwatch",
+                WatchMode::Timer(..) => "timer",
+                WatchMode::Clock => "clock"
+            },
+
+            stopwatch_time: SaveDataCommand::format_duration(
+                save_data.stopwatch_elapsed
+            ),
+
+            stopwatch_running: save_data.stopwatch_running.map(
+                |saved_at| saved_at.to_rfc3339()
+            ),
+
+            timer_time: save_data.timer_remaining.map(
+                SaveDataCommand::format_duration
+            ),
+
+            timer_phase: save_data.timer_phase,
+
+            laps: save_data.laps.iter().map(|lap_time| LapYaml {
+                split: SaveDataCommand::format_time(&lap_time.split),
+                lap: SaveDataCommand::format_time(&lap_time.lap)
+            }).collect(),
+
+            skin_overrides: SkinOverridesYaml {
+                stopwatch: save_data.skin_overrides.stopwatch.map(skin_yaml),
+                timer: save_data.skin_overrides.timer.map(skin_yaml),
+                clock: save_data.skin_overrides.clock.map(skin_yaml)
+            },
+
+            alarm_ringing: match save_data.alarm_state {
+                SavedAlarmState::Ringing(index) => Some(index),
+                _ => None
+            },
+
+            alarm_snoozed: match save_data.alarm_state {
+                SavedAlarmState::Snoozed {index, until} =>
+                    Some(AlarmSnoozedYaml {
+                        index: index,
+                        until: until.to_rfc3339()
+                    }),
+
+                _ => None
+            }
+        }
+    }
+}
+
+/// `anipwatch save-data show|set|reset [options] <file>`
+///
+/// Runs without opening a window.
+pub struct SaveDataCommand;
+
+impl SaveDataCommand {
+    #[inline]
+    pub fn is_requested(args: &[String]) -> bool {
+        args.get(1).map(|arg| arg == SUBCOMMAND).unwrap_or(false)
+    }
+
+    pub fn run(args: &[String]) -> ExitCode {
+        match Self::run_core(args) {
+            Ok(()) => ExitCode::SUCCESS,
+
+            Err(Error::ApplicationError(
+                ApplicationError::OnlyShowHelp(help)
+            )) => {
+                eprintln!("{}", help);
+                ExitCode::SUCCESS
+            },
+
+            Err(Error::ApplicationError(
+                ApplicationError::CommandOptionError(help)
+            )) => {
+                eprintln!("{}", help);
+                ExitCode::FAILURE
+            },
+
+            Err(error) => {
+                error.error_log();
+                ExitCode::FAILURE
+            }
+        }
+    }
+
+    fn run_core(args: &[String]) -> Result<(), Error> {
+        let opts = Self::gen_options();
+
+        let usage_error = || Error::from(
+            ApplicationError::CommandOptionError(
+                Self::gen_usage(&opts, &args[0])
+            )
+        );
+
+        let matches = opts.parse(&args[2..]).map_err(|_| usage_error())?;
+
+        if matches.opt_present("h") {
+            return Err(Error::from(ApplicationError::OnlyShowHelp(
+                Self::gen_usage(&opts, &args[0])
+            )));
+        }
+
+        let (command, file) = match matches.free.as_slice() {
+            [command, file] => (command.as_str(), Path::new(file)),
+            _ => {return Err(usage_error());}
+        };
+
+        let settings = match matches.opt_str("settings") {
+            Some(settings_file) =>
+                Some(Application::read_settings_file(&settings_file)?),
+
+            None => None
+        };
+
+        match command {
+            "show" => Self::show(file, settings.as_ref()),
+            "set" => Self::set(file, &matches),
+
+            "reset" => {
+                let mode = match (matches.opt_str("m"), &settings) {
+                    (Some(mode), _) => mode,
+                    (None, Some(settings)) => settings.default_mode.clone(),
+                    (None, None) => {return Err(usage_error());}
+                };
+
+                Self::reset(file, SettingsLoader::load_default_mode(&mode)?)
+            },
+
+            _ => Err(usage_error())
+        }
+    }
+
+    fn gen_options() -> Options {
+        let mut ret = Options::new();
+
+        ret.optflag("h", "help", "print usage");
+        ret.optopt(
+            "",
+            "settings",
+            "[show] print skin names, [reset] use its default_mode",
+            "SETTINGS_FILE"
+        );
+        ret.optopt(
+            "m",
+            "mode",
+            "[set|reset] watch mode",
+            "clock|stopwatch|timer"
+        );
+        ret.optopt(
+            "s",
+            "stopwatch-time",
+            "[set] stopwatch time",
+            "HH:MM:SS.CC"
+        );
+        ret.optopt(
+            "t",
+            "timer-time",
+            "[set] remaining time of timer",
+            "HH:MM:SS.CC"
+        );
+        ret.optopt(
+            "",
+            "timer-phase",
+            "[set] index of current timer phase",
+            "INDEX"
+        );
+        ret.optflag("", "clear-laps", "[set] remove all lap times");
+        ret.optflag(
+            "",
+            "clear-skin-overrides",
+            "[set] use skins of events and defaults in every mode"
+        );
+        ret.optflag(
+            "",
+            "clear-alarm",
+            "[set] stop the ringing or snoozed alarm"
+        );
+
+        ret
+    }
+
+    #[inline]
+    fn gen_usage(opts: &Options, program_name: &str) -> String {
+        opts.usage(format!(
+            "{} {} show|set|reset [options] SAVE_DATA_FILE",
+            program_name,
+            SUBCOMMAND
+        ).as_str())
+    }
+
+    fn show(file: &Path, settings: Option<&Settings>) -> Result<(), Error> {
+        let save_data = SettingsLoader::load_save_data(file)?;
+
+        print!(
+            "{}",
+            serde_yaml::to_string(&SaveDataYaml::new(&save_data, settings))?
+        );
+
+        Ok(())
+    }
+
+    fn set(file: &Path, matches: &Matches) -> Result<(), Error> {
+        let mut save_data = SettingsLoader::load_save_data(file)?;
+
+        Self::apply_options(&mut save_data, matches)?;
+
+        Ok(save_data.write_file(file, &mut Vec::<u8>::new())?)
+    }
+
+    // Writes the same save data as the first launch of the application.
+    // The previous file is kept as `<file>.bak`.
+    fn reset(file: &Path, watch_mode: WatchMode) -> Result<(), Error> {
+        let mut save_data = SaveData::new(watch_mode);
+
+        Ok(save_data.write_file(file, &mut Vec::<u8>::new())?)
+    }
+
+    fn apply_options(
+        save_data: &mut SaveData,
+        matches: &Matches
+    ) -> Result<(), Error> {
+        if let Some(mode) = matches.opt_str("m") {
+            save_data.watch_mode = SettingsLoader::load_default_mode(&mode)?;
+        }
+
+        if let Some(time) = matches.opt_str("s") {
+            save_data.stopwatch_elapsed =
+                Self::parse_option_time("stopwatch-time", &time)?;
+        }
+
+        if let Some(time) = matches.opt_str("t") {
+            save_data.timer_remaining =
+                Some(Self::parse_option_time("timer-time", &time)?);
+        }
+
+        if let Some(phase) = matches.opt_str("timer-phase") {
+            save_data.timer_phase = phase.parse::<usize>().map_err(
+                |_| Error::from(ApplicationError::InvalidOptionValue {
+                    option: "timer-phase".to_string(),
+                    value: phase.clone()
+                })
+            )?;
+        }
+
+        if matches.opt_present("clear-laps") {
+            save_data.laps.clear();
+        }
+
+        if matches.opt_present("clear-skin-overrides") {
+            save_data.skin_overrides = SkinOverrides::default();
+        }
+
+        if matches.opt_present("clear-alarm") {
+            save_data.alarm_state = SavedAlarmState::Idle;
+        }
+
+        Ok(())
+    }
+
+    fn parse_option_time(option: &str, time: &str) -> Result<Duration, Error> {
+        Self::parse_time(time).map(|time| time.to_duration()).ok_or_else(
+            || Error::from(ApplicationError::InvalidOptionValue {
+                option: option.to_string(),
+                value: time.to_string()
+            })
+        )
+    }
+
+    // "HH:MM:SS.CC" or "HH:MM:SS"
+    #[allow(clippy::redundant_field_names)]
+    fn parse_time(time: &str) -> Option<WatchTime> {
+        let (time, centiseconds) = match time.split_once('.') {
+            Some((time, cents)) if cents.len() == 2 =>
+                (time, cents.parse::<u32>().ok()?),
+
+            Some(..) => {return None;},
+            None => (time, 0)
+        };
+
+        let mut iter = time.split(':');
+
+        let hours = iter.next()?.parse::<u32>().ok()?;
+        let minutes = iter.next()?.parse::<u32>().ok()?;
+        let seconds = iter.next()?.parse::<u32>().ok()?;
+
+        if iter.next().is_some() {
+            return None;
+        }
+
+        SettingsLoader::load_time(&TimeElement {
+            centiseconds: centiseconds,
+            seconds: seconds,
+            minutes: minutes,
+            hours: hours
+        }).ok()
+    }
+
+    #[inline]
+    fn format_duration(duration: Duration) -> String {
+        Self::format_time(&WatchTime::from_duration(duration))
+    }
+
+    #[inline]
+    fn format_time(time: &WatchTime) -> String {
+        format!(
+            "{:02}:{:02}:{:02}.{:02}",
+            time.hours,
+            time.minutes,
+            time.seconds,
+            time.cents
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn parse_time_test() {
+        let time = WatchTime {
+            hours: 12,
+            minutes: 34,
+            seconds: 56,
+            cents: 78
+        };
+
+        assert_eq!(SaveDataCommand::format_time(&time), "12:34:56.78");
+        assert_eq!(SaveDataCommand::parse_time("12:34:56.78"), Some(time));
+
+        assert_eq!(
+            SaveDataCommand::parse_time("1:02:03"),
+            Some(WatchTime {
+                hours: 1,
+                minutes: 2,
+                seconds: 3,
+                cents: 0
+            })
+        );
+
+        assert_eq!(SaveDataCommand::parse_time("1:60:03"), None);
+        assert_eq!(SaveDataCommand::parse_time("1:02:03.4"), None);
+        assert_eq!(SaveDataCommand::parse_time("1:02"), None);
+        assert_eq!(SaveDataCommand::parse_time("1:02:03:04"), None);
+    }
+
+    #[test]
+    #[allow(clippy::redundant_field_names)]
+    fn apply_options_test() {
+        let until = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+
+        let mut save_data = SaveData::default();
+
+        save_data.laps = vec![LapTime::default()];
+        save_data.timer_phase = 1;
+        save_data.skin_overrides = SkinOverrides {
+            stopwatch: Some(1),
+            timer: None,
+            clock: Some(3)
+        };
+        save_data.alarm_state =
+            SavedAlarmState::Snoozed {index: 2, until: until};
+
+        let yaml = SaveDataYaml::new(&save_data, None);
+
+        assert_eq!(yaml.timer_phase, 1);
+        assert_eq!(yaml.skin_overrides, SkinOverridesYaml {
+            stopwatch: Some(SkinYaml {name: None, hash: 1}),
+            timer: None,
+            clock: Some(SkinYaml {name: None, hash: 3})
+        });
+        assert_eq!(yaml.alarm_ringing, None);
+        assert_eq!(yaml.alarm_snoozed, Some(AlarmSnoozedYaml {
+            index: 2,
+            until: "2024-01-02T03:04:05+00:00".to_string()
+        }));
+
+        save_data.alarm_state = SavedAlarmState::Ringing(4);
+
+        let yaml = SaveDataYaml::new(&save_data, None);
+
+        assert_eq!(yaml.alarm_ringing, Some(4));
+        assert_eq!(yaml.alarm_snoozed, None);
+
+        let matches = SaveDataCommand::gen_options().parse(&[
+            "--mode", "stopwatch",
+            "-s", "00:01:02.03",
+            "--timer-time", "00:05:00",
+            "--timer-phase", "0",
+            "--clear-laps",
+            "--clear-skin-overrides",
+            "--clear-alarm",
+            "set", "file"
+        ]).unwrap();
+
+        assert!(
+            SaveDataCommand::apply_options(&mut save_data, &matches).is_ok()
+        );
+
+        let yaml = SaveDataYaml::new(&save_data, None);
+
+        assert_eq!(yaml.watch_mode, "stopwatch");
+        assert_eq!(yaml.stopwatch_time, "00:01:02.03");
+        assert_eq!(yaml.stopwatch_running, None);
+        assert_eq!(yaml.timer_time.as_deref(), Some("00:05:00.00"));
+        assert_eq!(yaml.timer_phase, 0);
+        assert!(yaml.laps.is_empty());
+        assert_eq!(yaml.skin_overrides, SkinOverridesYaml {
+            stopwatch: None,
+            timer: None,
+            clock: None
+        });
+        assert_eq!(yaml.alarm_ringing, None);
+        assert_eq!(yaml.alarm_snoozed, None);
+
+        for (option, value) in [
+            ("--stopwatch-time", "abc"),
+            ("--timer-phase", "-1")
+        ] {
+            let matches = SaveDataCommand::gen_options().parse(&[
+                option, value,
+                "set", "file"
+            ]).unwrap();
+
+            assert!(
+                SaveDataCommand::apply_options(&mut save_data, &matches)
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn show_skin_names_test() {
+        let settings = Application::read_settings_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/examples/example_settings.yaml"
+        )).unwrap();
+
+        let mut save_data = SaveData::default();
+
+        save_data.skin_overrides = SkinOverrides {
+            stopwatch: Some(fnv_1a_64(b"skin_white")),
+            timer: Some(fnv_1a_64(b"removed_skin")),
+            clock: None
+        };
+
+        let yaml = SaveDataYaml::new(&save_data, Some(&settings));
+
+        assert_eq!(yaml.skin_overrides, SkinOverridesYaml {
+            stopwatch: Some(SkinYaml {
+                name: Some("skin_white".to_string()),
+                hash: fnv_1a_64(b"skin_white")
+            }),
+            timer: Some(SkinYaml {
+                name: None,
+                hash: fnv_1a_64(b"removed_skin")
+            }),
+            clock: None
+        });
+    }
+
+    #[test]
+    fn reset_test() {
+        let dir = std::env::temp_dir().join(format!(
+            "anipwatch_reset_test_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("save.data");
+
+        let mut save_data = SaveData::new(WatchMode::Clock);
+        save_data.stopwatch_elapsed = Duration::from_secs(10);
+        save_data.laps = vec![LapTime::default()];
+        save_data.write_file(&path, &mut Vec::<u8>::new()).unwrap();
+
+        SaveDataCommand::reset(
+            &path,
+            WatchMode::Timer(TimerMode::Stopped)
+        ).unwrap();
+
+        assert_eq!(
+            SettingsLoader::load_save_data(&path).unwrap(),
+            SaveData::new(WatchMode::Timer(TimerMode::Stopped))
+        );
+
+        // the reset can be undone with the backup.
+        assert_eq!(
+            SettingsLoader::load_save_data(&SaveData::backup_path(&path))
+                .unwrap(),
+            save_data
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    error::Error,
+    state::{State, WatchMode, StopwatchMode, TimerMode},
+    view::View,
+    display_view::{DisplayView, TimeField, TimeFields}
+};
+
+use eframe::{
+    egui::{
+        Ui,
+        TextureId,
+        Rect,
+        Pos2,
+        Color32
+    }
+};
+
+use chobitlibs::chobit_ani_value::ChobitAniValue;
+
+/// Shows a separator like `:` between numbers.
+///
+/// - Clock mode : blinks at 1 Hz with the clock.
+/// - Running stopwatch and timer : stays on.
+/// - Stopped stopwatch and timer : blinks fast.
+///
+/// If `hidden_with` is specified, the separator is hidden
+/// while the field and all the higher fields are zero.
+pub struct SeparatorView {
+    depth: i32,
+
+    texture_id: TextureId,
+
+    region: Rect,
+    ani_value: ChobitAniValue,
+
+    hidden_with: Option<TimeField>,
+    has_days: bool,
+
+    // seconds since the skin has been shown. (modulo 1 second)
+    blink_time: f32
+}
+
+const ON_FRAME: usize = 0;
+const OFF_FRAME: usize = 1;
+
+const FAST_BLINK_HZ: f32 = 4.0;
+
+impl SeparatorView {
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(
+        depth: i32,
+        texture_id: TextureId,
+        region: Rect,
+        hidden_with: Option<TimeField>,
+        has_days: bool
+    ) -> Self {
+        Self {
+            depth: depth,
+
+            texture_id: texture_id,
+
+            region: region,
+            ani_value: ChobitAniValue::new(2, &[2], 1.0).expect(
+                "Error at SeparatorView::new()"
+            ),
+
+            hidden_with: hidden_with,
+            has_days: has_days,
+
+            blink_time: 0.0
+        }
+    }
+
+    fn is_hidden(&self, state: &State) -> bool {
+        match self.hidden_with {
+            Some(field) => TimeFields::new(
+                &DisplayView::current_time(state),
+                self.has_days
+            ).get(field).is_leading_zero(),
+
+            None => false
+        }
+    }
+
+    #[inline]
+    fn advance(&mut self, dt: f32) {
+        self.blink_time = (self.blink_time + dt) % 1.0;
+    }
+
+    fn is_on(&self, state: &State) -> bool {
+        match state.watch_mode {
+            WatchMode::Clock => state.current_clock_time.cents < 50,
+
+            WatchMode::Stopwatch(StopwatchMode::Running)
+                | WatchMode::Timer(TimerMode::Running) => true,
+
+            WatchMode::Stopwatch(..) | WatchMode::Timer(..) =>
+                (self.blink_time * FAST_BLINK_HZ).fract() < 0.5
+        }
+    }
+}
+
+impl View for SeparatorView {
+    #[inline]
+    fn depth(&self) -> i32 {self.depth}
+
+    fn ready(&mut self, _ui: &Ui, _state: &mut State) -> Result<(), Error> {
+        // nothing to do.
+        Ok(())
+    }
+
+    fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
+        self.advance(state.dt);
+
+        if self.is_hidden(state) {
+            return Ok(());
+        }
+
+        let frame = if self.is_on(state) {ON_FRAME} else {OFF_FRAME};
+
+        self.ani_value.set_frame(frame);
+        let (left, top, right, bottom) =
+            self.ani_value.uv_frame_left_top_right_bottom();
+
+        ui.painter().image(
+            self.texture_id,
+            self.region,
+            Rect::from_min_max(
+                Pos2::new(*left, *top),
+                Pos2::new(*right, *bottom)
+            ),
+            Color32::WHITE
+        );
+
+        Ok(())
+    }
+
+    fn init(&mut self, _state: &mut State) -> Result<(), Error> {
+        self.blink_time = 0.0;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use super::*;
+    use crate::state::WatchTime;
+
+    fn gen_separator(hidden_with: Option<TimeField>) -> SeparatorView {
+        SeparatorView::new(
+            0,
+            TextureId::default(),
+            Rect::NOTHING,
+            hidden_with,
+            false
+        )
+    }
+
+    #[test]
+    fn is_hidden_test() {
+        let mut state = State::default();
+        state.watch_mode = WatchMode::Stopwatch(StopwatchMode::Stopped);
+        state.current_stopwatch_time = WatchTime {
+            hours: 0,
+            minutes: 5,
+            seconds: 3,
+            cents: 21
+        };
+
+        // "5:03.21" hides the separator after hours.
+        assert!(gen_separator(Some(TimeField::Hours)).is_hidden(&state));
+        assert!(!gen_separator(Some(TimeField::Minutes)).is_hidden(&state));
+        assert!(!gen_separator(None).is_hidden(&state));
+
+        state.current_stopwatch_time.hours = 1;
+        assert!(!gen_separator(Some(TimeField::Hours)).is_hidden(&state));
+    }
+
+    #[test]
+    fn is_on_test() {
+        let mut separator = gen_separator(None);
+        let mut state = State::default();
+
+        // clock mode blinks at 1 Hz with the clock.
+        state.watch_mode = WatchMode::Clock;
+        state.current_clock_time.cents = 49;
+        assert!(separator.is_on(&state));
+        state.current_clock_time.cents = 50;
+        assert!(!separator.is_on(&state));
+
+        let running = [
+            WatchMode::Stopwatch(StopwatchMode::Running),
+            WatchMode::Timer(TimerMode::Running)
+        ];
+
+        let stopped = [
+            WatchMode::Stopwatch(StopwatchMode::Stopped),
+            WatchMode::Timer(TimerMode::Stopped),
+            WatchMode::Timer(TimerMode::Expired)
+        ];
+
+        // 4 Hz: on for 0.125 seconds and off for 0.125 seconds.
+        for (blink_time, is_on) in [
+            (0.0, true),
+            (0.1, true),
+            (0.13, false),
+            (0.2, false),
+            (0.26, true),
+            (0.9, false)
+        ] {
+            separator.blink_time = blink_time;
+
+            for watch_mode in running {
+                state.watch_mode = watch_mode;
+                assert!(separator.is_on(&state));
+            }
+
+            for watch_mode in stopped {
+                state.watch_mode = watch_mode;
+                assert_eq!(separator.is_on(&state), is_on);
+            }
+        }
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut separator = gen_separator(None);
+
+        separator.advance(0.5);
+        assert_eq!(separator.blink_time, 0.5);
+
+        // wraps around 1 second.
+        separator.advance(0.75);
+        assert!((separator.blink_time - 0.25).abs() < 1e-6);
+
+        separator.advance(3.0);
+        assert!((separator.blink_time - 0.25).abs() < 1e-6);
+    }
+}
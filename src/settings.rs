@@ -1,21 +1,55 @@
 use serde::Deserialize;
 
+use std::collections::BTreeMap;
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Settings {
     pub window_size: WindowSize,
 
     pub save_data_file: String,
+    #[serde(default)]
+    pub resume_running: bool,
+    pub autosave_interval: Option<f32>,
 
     pub textures: Vec<TextureElement>,
 
     pub skins: Vec<SkinElement>,
     pub default_mode: String,
+    pub clock_format: Option<String>,
+    pub clock_zone: Option<String>,
 
     pub default_stopwatch_skin_name: String,
+    pub default_timer_skin_name: Option<String>,
     pub default_clock_skin_name: String,
 
+    pub stopwatch_limit: Option<TimeElement>,
+    pub stopwatch_overflow: Option<String>,
+
+    pub timer_duration: Option<TimeElement>,
+    pub timer_expired_skin_name: Option<String>,
+    #[serde(default)]
+    pub timer_phases: Vec<TimerPhaseElement>,
+    #[serde(default)]
+    pub timer_phases_loop: bool,
+
     pub stopwatch_events: Vec<EventElement>,
-    pub clock_events: Vec<EventElement>
+    #[serde(default)]
+    pub timer_events: Vec<EventElement>,
+    pub clock_events: Vec<EventElement>,
+
+    #[serde(default)]
+    pub alarms: Vec<AlarmElement>,
+    pub alarm_snooze: Option<f32>,
+    pub alarm_sound_command: Option<Vec<String>>,
+
+    pub key_bindings: Option<Vec<KeyBindingElement>>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct KeyBindingElement {
+    pub key: String,
+    pub action: String,
+    pub skin_name: Option<String>
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -43,10 +77,20 @@ pub struct SkinElement {
     pub name: String,
 
     pub display: DisplayElement,
-    pub switch_button: SwitchBtnElement,
-    pub start_stop_button: StartStopBtnElement,
-    pub reset_button: ResetBtnElement,
-    pub quit_button: QuitBtnElement,
+    pub switch_button: Option<SwitchBtnElement>,
+    pub start_stop_button: Option<StartStopBtnElement>,
+    pub reset_button: Option<ResetBtnElement>,
+    pub quit_button: Option<QuitBtnElement>,
+    pub lap_button: Option<LapBtnElement>,
+    #[serde(default)]
+    pub buttons: Vec<ButtonElement>,
+    pub laps: Option<LapsElement>,
+    pub phase: Option<PhaseElement>,
+    pub am_pm: Option<AmPmElement>,
+    #[serde(default)]
+    pub world_clocks: Vec<WorldClockElement>,
+    #[serde(default)]
+    pub separators: Vec<SeparatorElement>,
     pub animations: Vec<AnimationElement>
 }
 
@@ -63,42 +107,136 @@ pub struct DisplayRegions {
     pub seconds: Option<NumberRegion>,
     pub minutes: Option<NumberRegion>,
     pub hours: Option<NumberRegion>,
+    pub days: Option<NumberRegion>,
+    pub year: Option<NumberRegion>,
+    pub month: Option<NumberRegion>,
+    pub day: Option<NumberRegion>,
+    pub weekday: Option<WeekdayElement>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WeekdayElement {
+    pub texture_name: String,
+    pub region: Region
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct NumberRegion {
     pub d1: Region,
-    pub d2: Region
+    pub d2: Region,
+
+    #[serde(default)]
+    pub hide_leading_zeros: bool,
+    #[serde(default)]
+    pub hide_when_zero: bool,
+
+    // `d3`, `d4`, ...
+    #[serde(flatten)]
+    pub more_digits: BTreeMap<String, Region>
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct SwitchBtnElement {
     pub texture_name: String,
     pub depth: i32,
-    pub region: Region
+    pub region: Region,
+    pub states: Option<usize>,
+    pub frames: Option<usize>
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct StartStopBtnElement {
     pub texture_name: String,
     pub depth: i32,
-    pub region: Region
+    pub region: Region,
+    pub states: Option<usize>
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ResetBtnElement {
     pub texture_name: String,
     pub depth: i32,
-    pub region: Region
+    pub region: Region,
+    pub states: Option<usize>
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct QuitBtnElement {
+    pub texture_name: String,
+    pub depth: i32,
+    pub region: Region,
+    pub states: Option<usize>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LapBtnElement {
+    pub texture_name: String,
+    pub depth: i32,
+    pub region: Region,
+    pub states: Option<usize>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ButtonElement {
+    pub texture_name: String,
+    pub depth: i32,
+    pub region: Region,
+    pub states: Option<usize>,
+    pub action: String,
+    pub skin_name: Option<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LapsElement {
+    pub texture_name: String,
+    pub depth: i32,
+    pub rows: Vec<LapRowRegions>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PhaseElement {
+    pub texture_name: String,
+    pub depth: i32,
+    pub number: NumberRegion
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AmPmElement {
     pub texture_name: String,
     pub depth: i32,
     pub region: Region
 }
 
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SeparatorElement {
+    pub texture_name: String,
+    pub depth: i32,
+    pub region: Region,
+    pub field: Option<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WorldClockElement {
+    pub texture_name: String,
+    pub depth: i32,
+    pub regions: DisplayRegions,
+    pub zone: String,
+    pub label: Option<LabelElement>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LabelElement {
+    pub texture_name: String,
+    pub region: Region
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LapRowRegions {
+    pub number: Option<NumberRegion>,
+    pub split: Option<DisplayRegions>,
+    pub lap: Option<DisplayRegions>
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct AnimationElement {
     pub texture_name: String,
@@ -106,7 +244,9 @@ pub struct AnimationElement {
     pub region: Region,
     pub frames: usize,
     pub fps: f32,
-    pub probability: Option<f32>
+    pub probability: Option<f32>,
+    #[serde(default)]
+    pub on_alarm: bool
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -122,6 +262,36 @@ pub struct EventElement {
     pub skin_name: String,
 
     pub from: TimeElement,
+
+    // only for clock_events.
+    #[serde(default)]
+    pub weekdays: Vec<String>,
+    pub date_from: Option<DateElement>,
+    pub date_to: Option<DateElement>,
+    #[serde(default)]
+    pub dates: Vec<DateElement>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TimerPhaseElement {
+    pub duration: TimeElement,
+    pub skin_name: Option<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AlarmElement {
+    pub time: TimeElement,
+    #[serde(default)]
+    pub weekdays: Vec<String>,
+    pub skin_name: Option<String>,
+    pub sound: Option<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DateElement {
+    pub year: Option<i32>,
+    pub month: u32,
+    pub day: u32
 }
 
 #[cfg(test)]
@@ -135,6 +305,8 @@ window_size:
     height: 222
 
 save_data_file: "SaveDataFile!"
+resume_running: true
+autosave_interval: 30.5
 
 textures:
     -
@@ -173,6 +345,7 @@ skins:
                         width: 15.0
                         height: 16.0
                 minutes:
+                    hide_leading_zeros: true
                     d1:
                         x: 17.0
                         y: 18.0
@@ -194,6 +367,23 @@ skins:
                         y: 30.0
                         width: 31.0
                         height: 32.0
+                    d3:
+                        x: 154.0
+                        y: 155.0
+                        width: 156.0
+                        height: 157.0
+                days:
+                    hide_when_zero: true
+                    d1:
+                        x: 158.0
+                        y: 159.0
+                        width: 160.0
+                        height: 161.0
+                    d2:
+                        x: 162.0
+                        y: 163.0
+                        width: 164.0
+                        height: 165.0
         switch_button:
             texture_name: "Texture2!"
             depth: 1
@@ -202,6 +392,7 @@ skins:
                 y: 34.0
                 width: 35.0
                 height: 36.0
+            frames: 3
         start_stop_button:
             texture_name: "Texture3!"
             depth: 2
@@ -210,6 +401,7 @@ skins:
                 y: 38.0
                 width: 39.0
                 height: 40.0
+            states: 4
         reset_button:
             texture_name: "Texture4!"
             depth: 3
@@ -226,6 +418,141 @@ skins:
                 y: 46.0
                 width: 47.0
                 height: 48.0
+        lap_button:
+            texture_name: "Texture14!"
+            depth: 13
+            region:
+                x: 94.0
+                y: 95.0
+                width: 96.0
+                height: 97.0
+        buttons:
+            -
+                texture_name: "Texture16!"
+                depth: 15
+                region:
+                    x: 110.0
+                    y: 111.0
+                    width: 112.0
+                    height: 113.0
+                states: 3
+                action: "next_skin"
+            -
+                texture_name: "Texture17!"
+                depth: 16
+                region:
+                    x: 114.0
+                    y: 115.0
+                    width: 116.0
+                    height: 117.0
+                action: "minimize"
+        laps:
+            texture_name: "Texture15!"
+            depth: 14
+            rows:
+                -
+                    number:
+                        d1:
+                            x: 98.0
+                            y: 99.0
+                            width: 100.0
+                            height: 101.0
+                        d2:
+                            x: 102.0
+                            y: 103.0
+                            width: 104.0
+                            height: 105.0
+                    lap:
+                        seconds:
+                            d1:
+                                x: 106.0
+                                y: 107.0
+                                width: 108.0
+                                height: 109.0
+                            d2:
+                                x: 110.0
+                                y: 111.0
+                                width: 112.0
+                                height: 113.0
+        phase:
+            texture_name: "Texture16!"
+            depth: 15
+            number:
+                d1:
+                    x: 118.0
+                    y: 119.0
+                    width: 120.0
+                    height: 121.0
+                d2:
+                    x: 122.0
+                    y: 123.0
+                    width: 124.0
+                    height: 125.0
+        am_pm:
+            texture_name: "Texture17!"
+            depth: 16
+            region:
+                x: 126.0
+                y: 127.0
+                width: 128.0
+                height: 129.0
+        world_clocks:
+            -
+                texture_name: "Texture18!"
+                depth: 17
+                zone: "America/New_York"
+                regions:
+                    hours:
+                        d1:
+                            x: 130.0
+                            y: 131.0
+                            width: 132.0
+                            height: 133.0
+                        d2:
+                            x: 134.0
+                            y: 135.0
+                            width: 136.0
+                            height: 137.0
+                    day:
+                        d1:
+                            x: 142.0
+                            y: 143.0
+                            width: 144.0
+                            height: 145.0
+                        d2:
+                            x: 146.0
+                            y: 147.0
+                            width: 148.0
+                            height: 149.0
+                    weekday:
+                        texture_name: "Texture20!"
+                        region:
+                            x: 150.0
+                            y: 151.0
+                            width: 152.0
+                            height: 153.0
+                label:
+                    texture_name: "Texture19!"
+                    region:
+                        x: 138.0
+                        y: 139.0
+                        width: 140.0
+                        height: 141.0
+            -
+                texture_name: "Texture18!"
+                depth: 18
+                zone: "+09:00"
+                regions: {}
+        separators:
+            -
+                texture_name: "Texture21!"
+                depth: 19
+                region:
+                    x: 166.0
+                    y: 167.0
+                    width: 168.0
+                    height: 169.0
+                field: "hours"
         animations:
             -
                 texture_name: "Texture6!"
@@ -248,6 +575,7 @@ skins:
                     height: 57.0
                 frames: 2
                 fps: 2.0
+                on_alarm: true
     -
         name: "Skin2!"
         display:
@@ -322,8 +650,38 @@ skins:
                 probability: 3.0
 
 default_mode: "Clock!"
+clock_format: "12h"
+clock_zone: "Europe/London"
 default_stopwatch_skin_name: "Default-Stopwatch-Skin!"
+default_timer_skin_name: "Default-Timer-Skin!"
 default_clock_skin_name: "Default-Clock-Skin!"
+stopwatch_limit:
+    centiseconds: 0
+    seconds: 0
+    minutes: 0
+    hours: 1000
+stopwatch_overflow: "stop"
+timer_duration:
+    centiseconds: 20
+    seconds: 21
+    minutes: 22
+    hours: 23
+timer_expired_skin_name: "Timer-Expired-Skin!"
+timer_phases:
+    -
+        duration:
+            centiseconds: 0
+            seconds: 0
+            minutes: 25
+            hours: 0
+        skin_name: "Skin1!"
+    -
+        duration:
+            centiseconds: 0
+            seconds: 0
+            minutes: 5
+            hours: 0
+timer_phases_loop: true
 stopwatch_events:
     -
         skin_name: "Skin1!"
@@ -339,6 +697,14 @@ stopwatch_events:
             seconds: 9
             minutes: 10
             hours: 11
+timer_events:
+    -
+        skin_name: "Skin4!"
+        from:
+            centiseconds: 24
+            seconds: 25
+            minutes: 26
+            hours: 27
 clock_events:
     -
         skin_name: "Skin3!"
@@ -347,6 +713,47 @@ clock_events:
             seconds: 17
             minutes: 18
             hours: 19
+        weekdays: ["sat", "Sunday"]
+        date_from:
+            month: 12
+            day: 20
+        date_to:
+            month: 1
+            day: 5
+        dates:
+            -
+                year: 2030
+                month: 1
+                day: 1
+key_bindings:
+    -
+        key: "Space"
+        action: "start_stop"
+    -
+        key: "Q"
+        action: "quit"
+    -
+        key: "F1"
+        action: "select_skin"
+        skin_name: "Skin2!"
+alarms:
+    -
+        time:
+            centiseconds: 0
+            seconds: 0
+            minutes: 30
+            hours: 7
+        weekdays: ["mon", "fri"]
+        skin_name: "Skin1!"
+        sound: "Alarm!"
+    -
+        time:
+            centiseconds: 0
+            seconds: 0
+            minutes: 0
+            hours: 12
+alarm_snooze: 60.5
+alarm_sound_command: ["aplay", "-q"]
 "#;
 
     #[test]
@@ -365,6 +772,8 @@ clock_events:
             settings.save_data_file.as_str(),
             "SaveDataFile!"
         );
+        assert!(settings.resume_running);
+        assert_eq!(settings.autosave_interval, Some(30.5));
         assert_eq!(
             settings.textures.len(),
             2
@@ -786,99 +1195,110 @@ clock_events:
             32.0
         );
         assert_eq!(
-            settings.skins[0].switch_button.texture_name,
+            settings.skins[0].switch_button.as_ref().unwrap().texture_name,
             "Texture2!"
         );
         assert_eq!(
-            settings.skins[0].switch_button.depth,
+            settings.skins[0].switch_button.as_ref().unwrap().depth,
             1
         );
         assert_eq!(
-            settings.skins[0].switch_button.region.x,
+            settings.skins[0].switch_button.as_ref().unwrap().region.x,
             33.0
         );
         assert_eq!(
-            settings.skins[0].switch_button.region.y,
+            settings.skins[0].switch_button.as_ref().unwrap().region.y,
             34.0
         );
         assert_eq!(
-            settings.skins[0].switch_button.region.width,
+            settings.skins[0].switch_button.as_ref().unwrap().region.width,
             35.0
         );
         assert_eq!(
-            settings.skins[0].switch_button.region.height,
+            settings.skins[0].switch_button.as_ref().unwrap().region.height,
             36.0
         );
         assert_eq!(
-            settings.skins[0].start_stop_button.texture_name,
+            settings.skins[0].switch_button.as_ref().unwrap().frames,
+            Some(3)
+        );
+        assert_eq!(
+            settings.skins[0].start_stop_button.as_ref().unwrap().texture_name,
             "Texture3!"
         );
         assert_eq!(
-            settings.skins[0].start_stop_button.depth,
+            settings.skins[0].start_stop_button.as_ref().unwrap().depth,
             2
         );
         assert_eq!(
-            settings.skins[0].start_stop_button.region.x,
+            settings.skins[0].start_stop_button.as_ref().unwrap().region.x,
             37.0
         );
         assert_eq!(
-            settings.skins[0].start_stop_button.region.y,
+            settings.skins[0].start_stop_button.as_ref().unwrap().region.y,
             38.0
         );
         assert_eq!(
-            settings.skins[0].start_stop_button.region.width,
+            settings.skins[0].start_stop_button.as_ref().unwrap().region.width,
             39.0
         );
         assert_eq!(
-            settings.skins[0].start_stop_button.region.height,
+            settings.skins[0].start_stop_button.as_ref().unwrap().region.height,
             40.0
         );
         assert_eq!(
-            settings.skins[0].reset_button.texture_name,
+            settings.skins[0].start_stop_button.as_ref().unwrap().states,
+            Some(4)
+        );
+        assert!(
+            settings.skins[0].reset_button.as_ref().unwrap().states.is_none()
+        );
+        assert_eq!(
+            settings.skins[0].reset_button.as_ref().unwrap().texture_name,
             "Texture4!"
         );
         assert_eq!(
-            settings.skins[0].reset_button.depth,
+            settings.skins[0].reset_button.as_ref().unwrap().depth,
             3
         );
         assert_eq!(
-            settings.skins[0].reset_button.region.x,
+            settings.skins[0].reset_button.as_ref().unwrap().region.x,
             41.0
         );
         assert_eq!(
-            settings.skins[0].reset_button.region.y,
+            settings.skins[0].reset_button.as_ref().unwrap().region.y,
             42.0
         );
         assert_eq!(
-            settings.skins[0].reset_button.region.width,
+            settings.skins[0].reset_button.as_ref().unwrap().region.width,
             43.0
         );
         assert_eq!(
-            settings.skins[0].reset_button.region.height,
+            settings.skins[0].reset_button.as_ref().unwrap().region.height,
             44.0
         );
         assert_eq!(
-            settings.skins[0].quit_button.texture_name,
+            settings.skins[0].quit_button.as_ref().unwrap().texture_name,
             "Texture5!"
         );
         assert_eq!(
-            settings.skins[0].quit_button.depth,
+            settings.skins[0].quit_button.as_ref().unwrap().depth,
             4
         );
         assert_eq!(
-            settings.skins[0].quit_button.region.x,
+            settings.skins[0].quit_button.as_ref().unwrap().region.x,
             45.0
         );
         assert_eq!(
-            settings.skins[0].quit_button.region.y,
+            settings.skins[0].quit_button.as_ref().unwrap().region.y,
             46.0
         );
         assert_eq!(
-            settings.skins[0].quit_button.region.width,
+            settings.skins[0].quit_button.as_ref().unwrap().region.width,
             47.0
         );
         assert_eq!(
-            settings.skins[0].quit_button.region.height,
+            settings.skins[0].quit_button.as_ref().unwrap().region.height,
             48.0
         );
         assert_eq!(
@@ -954,6 +1374,8 @@ clock_events:
             2.0
         );
         assert!(settings.skins[0].animations[1].probability.is_none());
+        assert!(!settings.skins[0].animations[0].on_alarm);
+        assert!(settings.skins[0].animations[1].on_alarm);
         assert_eq!(
             settings.skins[1].name,
             "Skin2!"
@@ -1161,99 +1583,102 @@ clock_events:
             73.0
         );
         assert_eq!(
-            settings.skins[1].switch_button.texture_name,
+            settings.skins[1].switch_button.as_ref().unwrap().texture_name,
             "Texture9!"
         );
         assert_eq!(
-            settings.skins[1].switch_button.depth,
+            settings.skins[1].switch_button.as_ref().unwrap().depth,
             8
         );
         assert_eq!(
-            settings.skins[1].switch_button.region.x,
+            settings.skins[1].switch_button.as_ref().unwrap().region.x,
             74.0
         );
         assert_eq!(
-            settings.skins[1].switch_button.region.y,
+            settings.skins[1].switch_button.as_ref().unwrap().region.y,
             75.0
         );
         assert_eq!(
-            settings.skins[1].switch_button.region.width,
+            settings.skins[1].switch_button.as_ref().unwrap().region.width,
             76.0
         );
         assert_eq!(
-            settings.skins[1].switch_button.region.height,
+            settings.skins[1].switch_button.as_ref().unwrap().region.height,
             77.0
         );
+        assert!(
+            settings.skins[1].switch_button.as_ref().unwrap().frames.is_none()
+        );
         assert_eq!(
-            settings.skins[1].start_stop_button.texture_name,
+            settings.skins[1].start_stop_button.as_ref().unwrap().texture_name,
             "Texture10!"
         );
         assert_eq!(
-            settings.skins[1].start_stop_button.depth,
+            settings.skins[1].start_stop_button.as_ref().unwrap().depth,
             9
         );
         assert_eq!(
-            settings.skins[1].start_stop_button.region.x,
+            settings.skins[1].start_stop_button.as_ref().unwrap().region.x,
             78.0
         );
         assert_eq!(
-            settings.skins[1].start_stop_button.region.y,
+            settings.skins[1].start_stop_button.as_ref().unwrap().region.y,
             79.0
         );
         assert_eq!(
-            settings.skins[1].start_stop_button.region.width,
+            settings.skins[1].start_stop_button.as_ref().unwrap().region.width,
             80.0
         );
         assert_eq!(
-            settings.skins[1].start_stop_button.region.height,
+            settings.skins[1].start_stop_button.as_ref().unwrap().region.height,
             81.0
         );
         assert_eq!(
-            settings.skins[1].reset_button.texture_name,
+            settings.skins[1].reset_button.as_ref().unwrap().texture_name,
             "Texture11!"
         );
         assert_eq!(
-            settings.skins[1].reset_button.depth,
+            settings.skins[1].reset_button.as_ref().unwrap().depth,
             10
         );
         assert_eq!(
-            settings.skins[1].reset_button.region.x,
+            settings.skins[1].reset_button.as_ref().unwrap().region.x,
             82.0
         );
         assert_eq!(
-            settings.skins[1].reset_button.region.y,
+            settings.skins[1].reset_button.as_ref().unwrap().region.y,
             83.0
         );
         assert_eq!(
-            settings.skins[1].reset_button.region.width,
+            settings.skins[1].reset_button.as_ref().unwrap().region.width,
             84.0
         );
         assert_eq!(
-            settings.skins[1].reset_button.region.height,
+            settings.skins[1].reset_button.as_ref().unwrap().region.height,
             85.0
         );
         assert_eq!(
-            settings.skins[1].quit_button.texture_name,
+            settings.skins[1].quit_button.as_ref().unwrap().texture_name,
             "Texture12!"
         );
         assert_eq!(
-            settings.skins[1].quit_button.depth,
+            settings.skins[1].quit_button.as_ref().unwrap().depth,
             11
         );
         assert_eq!(
-            settings.skins[1].quit_button.region.x,
+            settings.skins[1].quit_button.as_ref().unwrap().region.x,
             86.0
         );
         assert_eq!(
-            settings.skins[1].quit_button.region.y,
+            settings.skins[1].quit_button.as_ref().unwrap().region.y,
             87.0
         );
         assert_eq!(
-            settings.skins[1].quit_button.region.width,
+            settings.skins[1].quit_button.as_ref().unwrap().region.width,
             88.0
         );
         assert_eq!(
-            settings.skins[1].quit_button.region.height,
+            settings.skins[1].quit_button.as_ref().unwrap().region.height,
             89.0
         );
         assert_eq!(
@@ -1300,6 +1725,73 @@ clock_events:
             settings.skins[1].animations[0].region.x,
             90.0
         );
+        assert_eq!(
+            settings.skins[0].lap_button.as_ref().unwrap().texture_name,
+            "Texture14!"
+        );
+        assert_eq!(
+            settings.skins[0].lap_button.as_ref().unwrap().depth,
+            13
+        );
+        assert_eq!(
+            settings.skins[0].lap_button.as_ref().unwrap().region.x,
+            94.0
+        );
+        assert_eq!(
+            settings.skins[0].laps.as_ref().unwrap().texture_name,
+            "Texture15!"
+        );
+        assert_eq!(
+            settings.skins[0].laps.as_ref().unwrap().rows.len(),
+            1
+        );
+        assert_eq!(
+            settings
+                .skins[0]
+                .laps
+                .as_ref()
+                .unwrap()
+                .rows[0]
+                .number
+                .as_ref()
+                .unwrap()
+                .d2
+                .x,
+            102.0
+        );
+        assert!(
+            settings.skins[0].laps.as_ref().unwrap().rows[0].split.is_none()
+        );
+        assert_eq!(
+            settings
+                .skins[0]
+                .laps
+                .as_ref()
+                .unwrap()
+                .rows[0]
+                .lap
+                .as_ref()
+                .unwrap()
+                .seconds
+                .as_ref()
+                .unwrap()
+                .d1
+                .x,
+            106.0
+        );
+        assert_eq!(settings.skins[0].buttons.len(), 2);
+        assert_eq!(settings.skins[0].buttons[0].texture_name, "Texture16!");
+        assert_eq!(settings.skins[0].buttons[0].depth, 15);
+        assert_eq!(settings.skins[0].buttons[0].region.x, 110.0);
+        assert_eq!(settings.skins[0].buttons[0].region.height, 113.0);
+        assert_eq!(settings.skins[0].buttons[0].states, Some(3));
+        assert_eq!(settings.skins[0].buttons[0].action, "next_skin");
+        assert_eq!(settings.skins[0].buttons[1].texture_name, "Texture17!");
+        assert!(settings.skins[0].buttons[1].states.is_none());
+        assert_eq!(settings.skins[0].buttons[1].action, "minimize");
+        assert!(settings.skins[1].buttons.is_empty());
+        assert!(settings.skins[1].lap_button.is_none());
+        assert!(settings.skins[1].laps.is_none());
         assert_eq!(
             settings.default_mode,
             "Clock!"
@@ -1308,10 +1800,184 @@ clock_events:
             settings.default_stopwatch_skin_name,
             "Default-Stopwatch-Skin!"
         );
+        assert_eq!(
+            settings.default_timer_skin_name.as_ref().unwrap(),
+            "Default-Timer-Skin!"
+        );
         assert_eq!(
             settings.default_clock_skin_name,
             "Default-Clock-Skin!"
         );
+        assert_eq!(
+            settings.skins[0].display.regions.hours.as_ref().unwrap()
+                .more_digits.get("d3"),
+            Some(&Region {
+                x: 154.0,
+                y: 155.0,
+                width: 156.0,
+                height: 157.0
+            })
+        );
+        assert_eq!(
+            settings.skins[0].display.regions.hours.as_ref().unwrap()
+                .more_digits.len(),
+            1
+        );
+        assert!(
+            settings.skins[0].display.regions.minutes.as_ref().unwrap()
+                .more_digits.is_empty()
+        );
+        assert_eq!(
+            settings.skins[0].display.regions.days.as_ref().unwrap().d2.x,
+            162.0
+        );
+        assert!(settings.skins[1].display.regions.days.is_none());
+        assert!(
+            settings.skins[0].display.regions.days.as_ref().unwrap()
+                .hide_when_zero
+        );
+        assert!(
+            !settings.skins[0].display.regions.days.as_ref().unwrap()
+                .hide_leading_zeros
+        );
+        assert!(
+            settings.skins[0].display.regions.minutes.as_ref().unwrap()
+                .hide_leading_zeros
+        );
+        assert!(
+            !settings.skins[0].display.regions.hours.as_ref().unwrap()
+                .hide_when_zero
+        );
+        assert_eq!(settings.stopwatch_limit.as_ref().unwrap().hours, 1000);
+        assert_eq!(settings.stopwatch_overflow, Some("stop".to_string()));
+        assert_eq!(
+            settings.timer_duration.as_ref().unwrap().centiseconds,
+            20
+        );
+        assert_eq!(
+            settings.timer_duration.as_ref().unwrap().seconds,
+            21
+        );
+        assert_eq!(
+            settings.timer_duration.as_ref().unwrap().minutes,
+            22
+        );
+        assert_eq!(
+            settings.timer_duration.as_ref().unwrap().hours,
+            23
+        );
+        assert_eq!(
+            settings.timer_expired_skin_name.as_ref().unwrap(),
+            "Timer-Expired-Skin!"
+        );
+        assert_eq!(settings.timer_phases.len(), 2);
+        assert_eq!(settings.timer_phases[0].duration.minutes, 25);
+        assert_eq!(
+            settings.timer_phases[0].skin_name,
+            Some("Skin1!".to_string())
+        );
+        assert_eq!(settings.timer_phases[1].duration.minutes, 5);
+        assert!(settings.timer_phases[1].skin_name.is_none());
+        assert!(settings.timer_phases_loop);
+        assert_eq!(
+            settings.skins[0].phase.as_ref().unwrap().texture_name,
+            "Texture16!"
+        );
+        assert_eq!(settings.skins[0].phase.as_ref().unwrap().depth, 15);
+        assert_eq!(
+            settings.skins[0].phase.as_ref().unwrap().number.d2.height,
+            125.0
+        );
+        assert!(settings.skins[1].phase.is_none());
+        assert_eq!(
+            settings.skins[0].am_pm,
+            Some(AmPmElement {
+                texture_name: "Texture17!".to_string(),
+                depth: 16,
+                region: Region {
+                    x: 126.0,
+                    y: 127.0,
+                    width: 128.0,
+                    height: 129.0
+                }
+            })
+        );
+        assert!(settings.skins[1].am_pm.is_none());
+        assert_eq!(settings.skins[0].world_clocks.len(), 2);
+        assert_eq!(
+            settings.skins[0].world_clocks[0].zone,
+            "America/New_York"
+        );
+        assert_eq!(settings.skins[0].world_clocks[0].depth, 17);
+        assert_eq!(
+            settings.skins[0].world_clocks[0].regions.hours.as_ref().unwrap()
+                .d2.height,
+            137.0
+        );
+        assert!(settings.skins[0].world_clocks[0].regions.minutes.is_none());
+        assert!(settings.skins[0].world_clocks[0].regions.year.is_none());
+        assert_eq!(
+            settings.skins[0].world_clocks[0].regions.day.as_ref().unwrap()
+                .d1.x,
+            142.0
+        );
+        assert_eq!(
+            settings.skins[0].world_clocks[0].regions.weekday,
+            Some(WeekdayElement {
+                texture_name: "Texture20!".to_string(),
+                region: Region {
+                    x: 150.0,
+                    y: 151.0,
+                    width: 152.0,
+                    height: 153.0
+                }
+            })
+        );
+        assert!(settings.skins[1].display.regions.weekday.is_none());
+        assert_eq!(
+            settings.skins[0].world_clocks[0].label,
+            Some(LabelElement {
+                texture_name: "Texture19!".to_string(),
+                region: Region {
+                    x: 138.0,
+                    y: 139.0,
+                    width: 140.0,
+                    height: 141.0
+                }
+            })
+        );
+        assert_eq!(settings.skins[0].world_clocks[1].zone, "+09:00");
+        assert!(settings.skins[0].world_clocks[1].label.is_none());
+        assert!(settings.skins[1].world_clocks.is_empty());
+        assert_eq!(
+            settings.skins[0].separators,
+            vec![SeparatorElement {
+                texture_name: "Texture21!".to_string(),
+                depth: 19,
+                region: Region {
+                    x: 166.0,
+                    y: 167.0,
+                    width: 168.0,
+                    height: 169.0
+                },
+                field: Some("hours".to_string())
+            }]
+        );
+        assert!(settings.skins[1].separators.is_empty());
+        assert_eq!(settings.clock_zone, Some("Europe/London".to_string()));
+        assert_eq!(settings.clock_format, Some("12h".to_string()));
+        assert_eq!(
+            settings.timer_events.len(),
+            1
+        );
+        assert_eq!(
+            settings.timer_events[0].skin_name,
+            "Skin4!"
+        );
+        assert_eq!(
+            settings.timer_events[0].from.hours,
+            27
+        );
         assert_eq!(
             settings.stopwatch_events.len(),
             2
@@ -1380,5 +2046,54 @@ clock_events:
             settings.clock_events[0].from.hours,
             19
         );
+        assert_eq!(settings.clock_events[0].weekdays, vec!["sat", "Sunday"]);
+        assert_eq!(
+            settings.clock_events[0].date_from,
+            Some(DateElement {year: None, month: 12, day: 20})
+        );
+        assert_eq!(
+            settings.clock_events[0].date_to,
+            Some(DateElement {year: None, month: 1, day: 5})
+        );
+        assert_eq!(
+            settings.clock_events[0].dates,
+            vec![DateElement {year: Some(2030), month: 1, day: 1}]
+        );
+        assert!(settings.stopwatch_events[0].weekdays.is_empty());
+        assert!(settings.stopwatch_events[0].date_from.is_none());
+        assert_eq!(
+            settings.key_bindings,
+            Some(vec![
+                KeyBindingElement {
+                    key: "Space".to_string(),
+                    action: "start_stop".to_string(),
+                    skin_name: None
+                },
+                KeyBindingElement {
+                    key: "Q".to_string(),
+                    action: "quit".to_string(),
+                    skin_name: None
+                },
+                KeyBindingElement {
+                    key: "F1".to_string(),
+                    action: "select_skin".to_string(),
+                    skin_name: Some("Skin2!".to_string())
+                }
+            ])
+        );
+        assert_eq!(settings.alarms.len(), 2);
+        assert_eq!(settings.alarms[0].time.minutes, 30);
+        assert_eq!(settings.alarms[0].time.hours, 7);
+        assert_eq!(settings.alarms[0].weekdays, vec!["mon", "fri"]);
+        assert_eq!(settings.alarms[0].skin_name, Some("Skin1!".to_string()));
+        assert_eq!(settings.alarms[0].sound, Some("Alarm!".to_string()));
+        assert!(settings.alarms[1].weekdays.is_empty());
+        assert!(settings.alarms[1].skin_name.is_none());
+        assert!(settings.alarms[1].sound.is_none());
+        assert_eq!(settings.alarm_snooze, Some(60.5));
+        assert_eq!(
+            settings.alarm_sound_command,
+            Some(vec!["aplay".to_string(), "-q".to_string()])
+        );
     }
 }
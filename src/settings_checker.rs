@@ -0,0 +1,220 @@
+use crate::{
+    settings_loader::{SettingsLoader, LoadProblems},
+    application::Application
+};
+
+use eframe::egui::Context;
+
+use std::process::ExitCode;
+
+/// `anipwatch --check SETTINGS_FILE`
+///
+/// Loads every part of settings file without opening a window
+/// and reports all problems found.
+/// Warnings are reported too, but they don't fail the check.
+pub struct SettingsChecker;
+
+impl SettingsChecker {
+    #[inline]
+    pub fn is_requested(args: &[String]) -> bool {
+        args.iter().skip(1).any(|arg| arg == "--check")
+    }
+
+    pub fn run(args: &[String]) -> ExitCode {
+        let opts = Application::gen_options();
+        let usage = Application::gen_usage(&opts, &args[0]);
+
+        let settings_file_name = match opts.parse(&args[1..]) {
+            Ok(matches) => match matches.free.as_slice() {
+                [settings_file_name] => settings_file_name.clone(),
+
+                _ => {
+                    eprintln!("{}", usage);
+                    return ExitCode::FAILURE;
+                }
+            },
+
+            Err(..) => {
+                eprintln!("{}", usage);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for warning in Self::find_warnings(&settings_file_name) {
+            println!("warning: {}", warning);
+        }
+
+        let problems = Self::check_file(&settings_file_name);
+
+        if problems.is_empty() {
+            println!("{}: no problems found.", settings_file_name);
+
+            ExitCode::SUCCESS
+        } else {
+            println!(
+                "{}: {} problem(s) found.",
+                settings_file_name,
+                problems.len()
+            );
+
+            for problem in problems {
+                println!("- {}", problem);
+            }
+
+            ExitCode::FAILURE
+        }
+    }
+
+    /// Loads settings file in the same way as the application does.
+    pub fn check_file(settings_file_name: &str) -> Vec<String> {
+        let mut problems = LoadProblems::new();
+
+        // textures need a context, but nothing is drawn.
+        let _ = Application::load_settings(
+            &Context::default(),
+            settings_file_name,
+            &mut problems
+        );
+
+        problems.into_vec()
+    }
+
+    /// Finds settings which work but may not be what the user wants.
+    pub fn find_warnings(settings_file_name: &str) -> Vec<String> {
+        let mut warnings = LoadProblems::new();
+
+        // broken settings are reported by check_file().
+        if let Ok(settings) =
+            Application::read_settings_file(settings_file_name)
+        {
+            if let Ok(limit) =
+                SettingsLoader::load_stopwatch_limit(&settings.stopwatch_limit)
+            {
+                SettingsLoader::check_stopwatch_limit(
+                    &settings.skins,
+                    &limit,
+                    &mut warnings
+                );
+            }
+        }
+
+        warnings.into_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{KeyBindingElement, AlarmElement, TimeElement};
+    use std::path::Path;
+
+    const EXAMPLE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/example_settings.yaml"
+    );
+
+    #[test]
+    fn example_settings_test() {
+        assert_eq!(SettingsChecker::check_file(EXAMPLE), Vec::<String>::new());
+        assert_eq!(
+            SettingsChecker::find_warnings(EXAMPLE),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            SettingsChecker::find_warnings("not_found.yaml"),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn problems_test() {
+        let mut settings = Application::read_settings_file(EXAMPLE).unwrap();
+
+        settings.textures[0].path = "not_found.png".to_string();
+        settings.skins[2].name = settings.skins[0].name.clone();
+        settings.default_mode = "unknown_mode".to_string();
+        settings.clock_format = Some("13h".to_string());
+        settings.clock_zone = Some("+25:00".to_string());
+        settings.stopwatch_overflow = Some("clamp".to_string());
+        settings.default_stopwatch_skin_name = "unknown_skin_1".to_string();
+        settings.clock_events[0].skin_name = "unknown_skin_2".to_string();
+        settings.autosave_interval = Some(-1.0);
+        settings.key_bindings = Some(vec![
+            KeyBindingElement {
+                key: "Nope".to_string(),
+                action: "start_stop".to_string(),
+                skin_name: None
+            },
+            KeyBindingElement {
+                key: "F1".to_string(),
+                action: "select_skin".to_string(),
+                skin_name: Some("unknown_skin_3".to_string())
+            }
+        ]);
+        settings.alarms = vec![AlarmElement {
+            time: TimeElement {
+                centiseconds: 0,
+                seconds: 0,
+                minutes: 0,
+                hours: 24
+            },
+            weekdays: Vec::<String>::new(),
+            skin_name: Some("unknown_skin_4".to_string()),
+            sound: Some("alarm.wav".to_string())
+        }];
+        settings.alarm_snooze = Some(0.0);
+        settings.alarm_sound_command = None;
+
+        let mut problems = LoadProblems::new();
+        assert!(Application::load_settings_from(
+            &Context::default(),
+            settings,
+            Path::new(EXAMPLE).parent().unwrap(),
+            &mut problems
+        ).is_none());
+
+        let problems = problems.into_vec();
+
+        assert!(problems.iter().any(|p| p.starts_with("textures[0]")));
+        assert!(problems.iter().any(
+            |p| p.starts_with("skins[2]") && p.contains("DuplicatedSkinName")
+        ));
+        assert!(problems.iter().any(|p| p.starts_with("default_mode")));
+        assert!(problems.iter().any(|p| p.starts_with("clock_format")));
+        assert!(problems.iter().any(|p| p.starts_with("clock_zone")));
+        assert!(problems.iter().any(|p| p.starts_with("stopwatch_overflow")));
+        assert!(problems.iter().any(
+            |p| p.contains(r#""key": "default_stopwatch_skin_name""#)
+                && p.contains(r#""name": "unknown_skin_1""#)
+        ));
+        assert!(problems.iter().any(
+            |p| p.contains("ClockEventError")
+                && p.contains(r#""index": 0, "name": "unknown_skin_2""#)
+        ));
+        assert!(problems.iter().any(
+            |p| p.contains(r#""key": "key_bindings[1].skin_name""#)
+                && p.contains(r#""name": "unknown_skin_3""#)
+        ));
+        assert!(problems.iter().any(
+            |p| p.contains(r#""key": "alarms[0].skin_name""#)
+                && p.contains(r#""name": "unknown_skin_4""#)
+        ));
+        assert!(problems.iter().any(
+            |p| p.starts_with("alarms") && p.contains("AlarmError")
+        ));
+        assert!(problems.iter().any(|p| p.starts_with("alarm_snooze")));
+        assert!(problems.iter().any(
+            |p| p.starts_with("alarm_sound_command")
+                && p.contains("AlarmSoundCommandNotSpecified")
+        ));
+        assert!(problems.iter().any(|p| p.starts_with("autosave_interval")));
+        assert!(problems.iter().any(
+            |p| p.starts_with("key_bindings") && p.contains("InvalidKeyName")
+        ));
+
+        assert_eq!(
+            SettingsChecker::check_file("not_found.yaml").len(),
+            1
+        );
+    }
+}
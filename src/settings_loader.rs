@@ -3,11 +3,25 @@ use crate::{
     settings::*,
     state::*,
     skin::Skin,
-    display_view::{DisplayView, DisplayNumber},
+    display_view::{
+        DisplayView,
+        DisplayNumber,
+        DisplayTime,
+        DisplayDate,
+        DisplayWeekday,
+        TimeField
+    },
     switch_btn_view::SwitchBtnView,
     button_view::ButtonView,
+    lap_view::{LapView, LapRow},
+    phase_view::PhaseView,
+    am_pm_view::AmPmView,
+    separator_view::SeparatorView,
+    world_clock_view::WorldClockView,
+    time_zone::ClockZone,
     animation_view::AnimationView,
-    save_data::SaveData
+    key_bindings::KeyBindings,
+    save_data::{SaveData, CURRENT_VERSION}
 };
 
 use chobitlibs::{
@@ -17,11 +31,11 @@ use chobitlibs::{
     chobit_ani_value::ChobitAniValue
 };
 
-use image;
-
 use eframe::egui::{
     Context,
+    Key,
     TextureHandle,
+    TextureId,
     ColorImage,
     Rect,
     Pos2,
@@ -34,7 +48,8 @@ use std::{
     rc::Rc,
     cell::RefCell,
     fs::OpenOptions,
-    io::{BufReader, Read}
+    io::{BufReader, Read},
+    time::Duration
 };
 
 use chrono::prelude::*;
@@ -43,10 +58,36 @@ use chrono::prelude::*;
 pub enum LoadError {
     //ConflictedTextureName,
     StopwatchEventError(EventError),
+    TimerEventError(EventError),
     ClockEventError(EventError),
+    TimerDurationError(EventError),
+    TimerSkinNotSpecified,
+    TimerPhaseError {index: usize, error: EventError},
+    ZeroTimerPhase(usize),
+    TimerDurationAndPhases,
     TextureNotFound(String),
+    DuplicatedSkinName(String),
     InvalidDefaultMode(String),
-    CorruptedSaveData(String)
+    InvalidClockFormat(String),
+    InvalidTimeZone(String),
+    InvalidDigitName(String),
+    InvalidTimeField(String),
+    StopwatchLimitError(EventError),
+    ZeroStopwatchLimit,
+    StopwatchLimitNotShown {max_hours: u64},
+    InvalidStopwatchOverflow(String),
+    InvalidSwitchBtnFrames(usize),
+    InvalidButtonStates(usize),
+    InvalidAutosaveInterval(f32),
+    SkinNotFound {key: String, name: String},
+    InvalidKeyName(String),
+    InvalidButtonAction(String),
+    SkinNameNotSpecified(String),
+    AlarmError {index: usize, error: EventError},
+    InvalidAlarmSnooze(f32),
+    AlarmSoundCommandNotSpecified,
+    CorruptedSaveData(String),
+    NewerSaveData {file_path: String, version: u64}
 }
 
 impl fmt::Display for LoadError {
@@ -63,15 +104,54 @@ impl fmt::Display for LoadError {
                 <EventError as fmt::Display>::fmt(error, formatter)?;
             },
 
+            Self::TimerEventError(error) => {
+                write!(formatter, r#""TimerEventError", "data": "#)?;
+                <EventError as fmt::Display>::fmt(error, formatter)?;
+            },
+
             Self::ClockEventError(error) => {
                 write!(formatter, r#""ClockEventError", "data": "#)?;
                 <EventError as fmt::Display>::fmt(error, formatter)?;
             },
 
+            Self::TimerDurationError(error) => {
+                write!(formatter, r#""TimerDurationError", "data": "#)?;
+                <EventError as fmt::Display>::fmt(error, formatter)?;
+            },
+
+            Self::TimerSkinNotSpecified => {
+                write!(formatter, r#""TimerSkinNotSpecified""#)?;
+            },
+
+            Self::TimerPhaseError {index, error} => {
+                write!(
+                    formatter,
+                    r#""TimerPhaseError", "index": {}, "error": {}"#,
+                    index,
+                    error
+                )?;
+            },
+
+            Self::ZeroTimerPhase(index) => {
+                write!(formatter, r#""ZeroTimerPhase", "index": {}"#, index)?;
+            },
+
+            Self::TimerDurationAndPhases => {
+                write!(formatter, r#""TimerDurationAndPhases""#)?;
+            },
+
             Self::TextureNotFound(name) => {
                 write!(formatter, r#""TextureNotFound", "name": {}"#, name)?;
             },
 
+            Self::DuplicatedSkinName(name) => {
+                write!(
+                    formatter,
+                    r#""DuplicatedSkinName", "name": {:?}"#,
+                    name
+                )?;
+            },
+
             Self::InvalidDefaultMode(mode) => {
                 write!(
                     formatter,
@@ -80,6 +160,137 @@ impl fmt::Display for LoadError {
                 )?;
             },
 
+            Self::InvalidClockFormat(format) => {
+                write!(
+                    formatter,
+                    r#""InvalidClockFormat", "format": {:?}"#,
+                    format
+                )?;
+            },
+
+            Self::InvalidTimeZone(zone) => {
+                write!(
+                    formatter,
+                    r#""InvalidTimeZone", "zone": {:?}"#,
+                    zone
+                )?;
+            },
+
+            Self::InvalidDigitName(name) => {
+                write!(
+                    formatter,
+                    r#""InvalidDigitName", "name": {:?}"#,
+                    name
+                )?;
+            },
+
+            Self::InvalidTimeField(field) => {
+                write!(
+                    formatter,
+                    r#""InvalidTimeField", "field": {:?}"#,
+                    field
+                )?;
+            },
+
+            Self::StopwatchLimitError(error) => {
+                write!(formatter, r#""StopwatchLimitError", "data": "#)?;
+                <EventError as fmt::Display>::fmt(error, formatter)?;
+            },
+
+            Self::ZeroStopwatchLimit => {
+                write!(formatter, r#""ZeroStopwatchLimit""#)?;
+            },
+
+            Self::StopwatchLimitNotShown {max_hours} => {
+                write!(
+                    formatter,
+                    r#""StopwatchLimitNotShown", "max_hours": {}"#,
+                    max_hours
+                )?;
+            },
+
+            Self::InvalidStopwatchOverflow(overflow) => {
+                write!(
+                    formatter,
+                    r#""InvalidStopwatchOverflow", "overflow": {:?}"#,
+                    overflow
+                )?;
+            },
+
+            Self::InvalidSwitchBtnFrames(frames) => {
+                write!(
+                    formatter,
+                    r#""InvalidSwitchBtnFrames", "frames": {}"#,
+                    frames
+                )?;
+            },
+
+            Self::InvalidButtonStates(states) => {
+                write!(
+                    formatter,
+                    r#""InvalidButtonStates", "states": {}"#,
+                    states
+                )?;
+            },
+
+            Self::InvalidAutosaveInterval(interval) => {
+                write!(
+                    formatter,
+                    r#""InvalidAutosaveInterval", "interval": {}"#,
+                    interval
+                )?;
+            },
+
+            Self::SkinNotFound {key, name} => {
+                write!(
+                    formatter,
+                    r#""SkinNotFound", "key": {:?}, "name": {:?}"#,
+                    key,
+                    name
+                )?;
+            },
+
+            Self::InvalidKeyName(key) => {
+                write!(formatter, r#""InvalidKeyName", "key": {:?}"#, key)?;
+            },
+
+            Self::InvalidButtonAction(action) => {
+                write!(
+                    formatter,
+                    r#""InvalidButtonAction", "action": {:?}"#,
+                    action
+                )?;
+            },
+
+            Self::SkinNameNotSpecified(action) => {
+                write!(
+                    formatter,
+                    r#""SkinNameNotSpecified", "action": {:?}"#,
+                    action
+                )?;
+            },
+
+            Self::AlarmError {index, error} => {
+                write!(
+                    formatter,
+                    r#""AlarmError", "index": {}, "error": {}"#,
+                    index,
+                    error
+                )?;
+            },
+
+            Self::InvalidAlarmSnooze(snooze) => {
+                write!(
+                    formatter,
+                    r#""InvalidAlarmSnooze", "snooze": {}"#,
+                    snooze
+                )?;
+            },
+
+            Self::AlarmSoundCommandNotSpecified => {
+                write!(formatter, r#""AlarmSoundCommandNotSpecified""#)?;
+            },
+
             Self::CorruptedSaveData(file_path) => {
                 write!(
                     formatter,
@@ -87,6 +298,15 @@ impl fmt::Display for LoadError {
                     file_path
                 )?;
             },
+
+            Self::NewerSaveData {file_path, version} => {
+                write!(
+                    formatter,
+                    r#""NewerSaveData", "file_path": {}, "version": {}"#,
+                    file_path,
+                    version
+                )?;
+            },
         }
 
         write!(formatter, "}}")
@@ -96,10 +316,15 @@ impl fmt::Display for LoadError {
 #[derive(Debug, Clone, PartialEq)]
 pub enum EventError {
     //SkinID(u64),
+    SkinNotFound {index: usize, name: String},
     Centiseconds(u32),
     Seconds(u32),
     Minutes(u32),
-    Hours(u32)
+    Hours(u32),
+    Weekday(String),
+    Date {year: Option<i32>, month: u32, day: u32},
+    DateRange,
+    DateCondition
 }
 
 impl fmt::Display for EventError {
@@ -111,6 +336,15 @@ impl fmt::Display for EventError {
             //    write!(formatter, r#""SkinID", "id": {}"#, id)?;
             //},
 
+            Self::SkinNotFound {index, name} => {
+                write!(
+                    formatter,
+                    r#""SkinNotFound", "index": {}, "name": {:?}"#,
+                    index,
+                    name
+                )?;
+            },
+
             Self::Centiseconds(time) => {
                 write!(formatter, r#""Centiseconds", "time": {}"#, time)?;
             },
@@ -126,60 +360,248 @@ impl fmt::Display for EventError {
             Self::Hours(time) => {
                 write!(formatter, r#""Hours", "time": {}"#, time)?;
             },
+
+            Self::Weekday(weekday) => {
+                write!(formatter, r#""Weekday", "weekday": {:?}"#, weekday)?;
+            },
+
+            Self::Date {year, month, day} => {
+                write!(
+                    formatter,
+                    r#""Date", "year": {:?}, "month": {}, "day": {}"#,
+                    year,
+                    month,
+                    day
+                )?;
+            },
+
+            Self::DateRange => {
+                write!(formatter, r#""DateRange""#)?;
+            },
+
+            Self::DateCondition => {
+                write!(formatter, r#""DateCondition""#)?;
+            },
         }
 
         write!(formatter, "}}")
     }
 }
 
+/// Skins shown in each mode when no event matches.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DefaultSkinIds {
+    pub stopwatch: u64,
+    pub timer: u64,
+    pub clock: u64
+}
+
+/// Problems found while loading settings file.
+///
+/// Each problem is `"place: error"`.
+/// Loading goes on after a problem so that all problems are reported.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LoadProblems {
+    problems: Vec<String>
+}
+
+impl LoadProblems {
+    #[inline]
+    pub fn new() -> Self {
+        Self {problems: Vec::<String>::new()}
+    }
+
+    /// Records the error of `result` and returns `None` if it is an error.
+    pub fn check<T>(
+        &mut self,
+        place: &str,
+        result: Result<T, Error>
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+
+            Err(error) => {
+                self.push(place, error);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, place: &str, error: Error) {
+        self.problems.push(format!("{}: {}", place, error));
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<String> {
+        self.problems
+    }
+}
+
 pub struct SettingsLoader;
 
 const TABLE_SIZE: usize = 32;
 
+type ToLoadError = fn(EventError) -> LoadError;
+
 impl SettingsLoader {
     #[inline]
     pub fn load_window_size(window_size: &WindowSize) -> Result<Vec2, Error> {
         Ok(Vec2::new(window_size.width, window_size.height))
     }
 
+    /// Falls back to the backup file if the save data file is broken.
     pub fn load_save_data_file<P1: AsRef<Path>, P2: AsRef<Path>>(
         root_dir: &P1,
         save_data_file: &P2
     ) -> Result<SaveData, Error> {
         let path = root_dir.as_ref().join(save_data_file.as_ref());
 
+        Self::load_save_data(&path).or_else(
+            |error| Self::load_save_data(
+                &SaveData::backup_path(&path)
+            ).map_err(|_| error)
+        )
+    }
+
+    pub fn load_save_data(path: &Path) -> Result<SaveData, Error> {
         let mut file =
-            BufReader::new(OpenOptions::new().read(true).open(&path)?);
+            BufReader::new(OpenOptions::new().read(true).open(path)?);
 
         let mut data = Vec::<u8>::new();
         file.read_to_end(&mut data)?;
 
-        SaveData::from_bytes(data.as_slice()).ok_or_else (
+        let save_data = SaveData::from_bytes(data.as_slice()).ok_or_else(
             || Error::from(LoadError::CorruptedSaveData(
                 path.to_string_lossy().into_owned()
             ))
-        )
+        )?;
+
+        // only known entries of a newer version are read.
+        if save_data.version > CURRENT_VERSION {
+            Error::from(LoadError::NewerSaveData {
+                file_path: path.to_string_lossy().into_owned(),
+                version: save_data.version
+            }).error_log();
+        }
+
+        Ok(save_data)
+    }
+
+    pub fn load_autosave_interval(
+        autosave_interval: &Option<f32>
+    ) -> Result<Option<Duration>, Error> {
+        match autosave_interval {
+            Some(interval) => match Duration::try_from_secs_f32(*interval) {
+                Ok(duration) if !duration.is_zero() => Ok(Some(duration)),
+
+                _ => Err(Error::from(
+                    LoadError::InvalidAutosaveInterval(*interval)
+                ))
+            },
+
+            None => Ok(None)
+        }
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    pub fn load_alarms<P: AsRef<Path>>(
+        root_dir: &P,
+        alarms: &[AlarmElement]
+    ) -> Result<Vec<Alarm>, Error> {
+        let mut ret = Vec::<Alarm>::with_capacity(alarms.len());
+
+        for (index, elm) in alarms.iter().enumerate() {
+            let to_load_error = |error: EventError| Error::from(
+                LoadError::AlarmError {index: index, error: error}
+            );
+
+            let time = Self::load_time(&elm.time).map_err(to_load_error)?;
+
+            // alarms are of clock time.
+            if time.hours > 23 {
+                return Err(to_load_error(EventError::Hours(time.hours)));
+            }
+
+            ret.push(Alarm {
+                time: time,
+                weekdays: Self::load_weekdays(&elm.weekdays)
+                    .map_err(to_load_error)?,
+                skin_id: elm.skin_name.as_ref().map(
+                    |name| fnv_1a_64(name.as_bytes())
+                ),
+                sound: elm.sound.as_ref().map(
+                    |path| root_dir.as_ref().join(path)
+                )
+            });
+        }
+
+        Ok(ret)
+    }
+
+    pub fn load_alarm_snooze(
+        alarm_snooze: &Option<f32>
+    ) -> Result<Duration, Error> {
+        match alarm_snooze {
+            Some(snooze) => match Duration::try_from_secs_f32(*snooze) {
+                Ok(duration) if !duration.is_zero() => Ok(duration),
+
+                _ => Err(Error::from(LoadError::InvalidAlarmSnooze(*snooze)))
+            },
+
+            None => Ok(Duration::from_secs(300))
+        }
+    }
+
+    /// Sound file path is appended to the command.
+    /// The command is required if any alarm has a sound.
+    pub fn load_alarm_sound_command(
+        alarm_sound_command: &Option<Vec<String>>,
+        alarms: &[AlarmElement]
+    ) -> Result<Vec<String>, Error> {
+        let command = alarm_sound_command.clone().unwrap_or_default();
+
+        if command.is_empty() && alarms.iter().any(|elm| elm.sound.is_some()) {
+            Err(Error::from(LoadError::AlarmSoundCommandNotSpecified))
+        } else {
+            Ok(command)
+        }
     }
 
     pub fn load_textures<P: AsRef<Path>>(
         ctx: &Context,
         root_dir: &P,
-        textures: &Vec<TextureElement>
-    ) -> Result<ChobitMap<TextureHandle>, Error> {
+        textures: &[TextureElement],
+        problems: &mut LoadProblems
+    ) -> ChobitMap<TextureHandle> {
         let mut map = ChobitMap::<TextureHandle>::new(TABLE_SIZE);
 
-        for elm in textures.as_slice() {
-            let key = fnv_1a_64(elm.name.as_bytes());
-            let texture =
-                Self::load_texture_from_path(ctx, root_dir, &elm.path)?;
+        for (index, elm) in textures.iter().enumerate() {
+            let place = format!("textures[{}] ({:?})", index, elm.name);
 
-            map.add(key, texture)?;
+            let texture = problems.check(
+                &place,
+                Self::load_texture_from_path(ctx, root_dir, &elm.path)
+            );
+
+            if let Some(texture) = texture {
+                problems.check(
+                    &place,
+                    map.add(fnv_1a_64(elm.name.as_bytes()), texture)
+                        .map_err(Error::from)
+                );
+            }
         }
 
-        Ok(map)
+        map
     }
 
-    fn load_texture_from_path<P1: AsRef<Path>, P2: AsRef<Path>>(
+    pub fn load_texture_from_path<P1: AsRef<Path>, P2: AsRef<Path>>(
         ctx: &Context,
         root_dir: &P1,
         path: &P2
@@ -200,27 +622,166 @@ impl SettingsLoader {
         Ok(ctx.load_texture("textures", color_img, Default::default()))
     }
 
+    /// Returns skins and a map from skin ID to skin name.
     pub fn load_skins(
-        skins: &Vec<SkinElement>,
-        textures: &ChobitMap<TextureHandle>
-    ) -> Result<ChobitMap<Rc<RefCell<Skin>>>, Error> {
+        skins: &[SkinElement],
+        textures: &ChobitMap<TextureHandle>,
+        problems: &mut LoadProblems
+    ) -> (ChobitMap<Rc<RefCell<Skin>>>, ChobitMap<String>) {
         let mut ret = ChobitMap::<Rc<RefCell<Skin>>>::new(TABLE_SIZE);
+        let mut skin_names = ChobitMap::<String>::new(TABLE_SIZE);
+
+        for (index, elm) in skins.iter().enumerate() {
+            let place = format!("skins[{}] ({:?})", index, elm.name);
 
-        for elm in skins.as_slice() {
             let skin_id = fnv_1a_64(elm.name.as_bytes());
 
-            ret.add(
-                skin_id,
-                Rc::new(RefCell::new(
-                    Self::load_one_skin(elm, textures)?
-                ))
-            )?;
+            if skin_names.add(skin_id, elm.name.clone()).is_err() {
+                problems.push(&place, Error::from(
+                    LoadError::DuplicatedSkinName(elm.name.clone())
+                ));
+
+                continue;
+            }
+
+            let skin = problems.check(
+                &place,
+                Self::load_one_skin(elm, textures)
+            );
+
+            if let Some(skin) = skin {
+                problems.check(
+                    &place,
+                    ret.add(skin_id, Rc::new(RefCell::new(skin)))
+                        .map_err(Error::from)
+                );
+            }
         }
 
-        Ok(ret)
+        (ret, skin_names)
     }
 
-    fn load_one_skin(
+    /// Reports every skin name that is referenced but not defined.
+    pub fn check_skin_names(
+        settings: &Settings,
+        skin_names: &ChobitMap<String>,
+        problems: &mut LoadProblems
+    ) {
+        for error in Self::find_unknown_skin_names(settings, skin_names) {
+            problems.push("skin names", Error::from(error));
+        }
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    pub fn find_unknown_skin_names(
+        settings: &Settings,
+        skin_names: &ChobitMap<String>
+    ) -> Vec<LoadError> {
+        let mut ret = Vec::<LoadError>::new();
+
+        let exists =
+            |name: &str| skin_names.get(fnv_1a_64(name.as_bytes())).is_some();
+
+        let default_names = [
+            (
+                "default_stopwatch_skin_name",
+                Some(&settings.default_stopwatch_skin_name)
+            ),
+            (
+                "default_timer_skin_name",
+                settings.default_timer_skin_name.as_ref()
+            ),
+            (
+                "default_clock_skin_name",
+                Some(&settings.default_clock_skin_name)
+            ),
+            (
+                "timer_expired_skin_name",
+                settings.timer_expired_skin_name.as_ref()
+            )
+        ];
+
+        for (key, name) in default_names {
+            if let Some(name) = name {
+                if !exists(name) {
+                    ret.push(LoadError::SkinNotFound {
+                        key: key.to_string(),
+                        name: name.clone()
+                    });
+                }
+            }
+        }
+
+        let mut action_names = Vec::<(String, &String)>::new();
+
+        for (i, skin) in settings.skins.iter().enumerate() {
+            for (j, elm) in skin.buttons.iter().enumerate() {
+                if let Some(name) = elm.skin_name.as_ref() {
+                    action_names.push((
+                        format!("skins[{}].buttons[{}].skin_name", i, j),
+                        name
+                    ));
+                }
+            }
+        }
+
+        for (i, elm) in settings.key_bindings.iter().flatten().enumerate() {
+            if let Some(name) = elm.skin_name.as_ref() {
+                action_names.push((
+                    format!("key_bindings[{}].skin_name", i),
+                    name
+                ));
+            }
+        }
+
+        for (i, elm) in settings.timer_phases.iter().enumerate() {
+            if let Some(name) = elm.skin_name.as_ref() {
+                action_names.push((
+                    format!("timer_phases[{}].skin_name", i),
+                    name
+                ));
+            }
+        }
+
+        for (i, elm) in settings.alarms.iter().enumerate() {
+            if let Some(name) = elm.skin_name.as_ref() {
+                action_names.push((
+                    format!("alarms[{}].skin_name", i),
+                    name
+                ));
+            }
+        }
+
+        for (key, name) in action_names {
+            if !exists(name) {
+                ret.push(LoadError::SkinNotFound {
+                    key: key,
+                    name: name.clone()
+                });
+            }
+        }
+
+        let events_list: [(&Vec<EventElement>, ToLoadError); 3] = [
+            (&settings.stopwatch_events, LoadError::StopwatchEventError),
+            (&settings.timer_events, LoadError::TimerEventError),
+            (&settings.clock_events, LoadError::ClockEventError)
+        ];
+
+        for (events, to_load_error) in events_list {
+            for (index, elm) in events.iter().enumerate() {
+                if !exists(&elm.skin_name) {
+                    ret.push(to_load_error(EventError::SkinNotFound {
+                        index: index,
+                        name: elm.skin_name.clone()
+                    }));
+                }
+            }
+        }
+
+        ret
+    }
+
+    pub fn load_one_skin(
         skin_elm: &SkinElement,
         textures: &ChobitMap<TextureHandle>
     ) -> Result<Skin, Error> {
@@ -231,25 +792,53 @@ impl SettingsLoader {
             textures
         )?));
 
-        skin.add(Box::new(Self::load_switch_btn_view(
-            &skin_elm.switch_button,
-            textures
-        )?));
+        if let Some(elm) = skin_elm.switch_button.as_ref() {
+            skin.add(Box::new(Self::load_switch_btn_view(elm, textures)?));
+        }
 
-        skin.add(Box::new(Self::load_start_stop_btn_view(
-            &skin_elm.start_stop_button,
-            textures
-        )?));
+        if let Some(elm) = skin_elm.start_stop_button.as_ref() {
+            skin.add(Box::new(Self::load_start_stop_btn_view(elm, textures)?));
+        }
 
-        skin.add(Box::new(Self::load_reset_btn_view(
-            &skin_elm.reset_button,
-            textures
-        )?));
+        if let Some(elm) = skin_elm.reset_button.as_ref() {
+            skin.add(Box::new(Self::load_reset_btn_view(elm, textures)?));
+        }
 
-        skin.add(Box::new(Self::load_quit_btn_view(
-            &skin_elm.quit_button,
-            textures
-        )?));
+        if let Some(elm) = skin_elm.quit_button.as_ref() {
+            skin.add(Box::new(Self::load_quit_btn_view(elm, textures)?));
+        }
+
+        if let Some(lap_elm) = skin_elm.lap_button.as_ref() {
+            skin.add(Box::new(Self::load_lap_btn_view(lap_elm, textures)?));
+        }
+
+        for elm in skin_elm.buttons.as_slice() {
+            skin.add(Box::new(Self::load_btn_view(elm, textures)?));
+        }
+
+        if let Some(laps_elm) = skin_elm.laps.as_ref() {
+            skin.add(Box::new(Self::load_lap_view(laps_elm, textures)?));
+        }
+
+        if let Some(phase_elm) = skin_elm.phase.as_ref() {
+            skin.add(Box::new(Self::load_phase_view(phase_elm, textures)?));
+        }
+
+        if let Some(am_pm_elm) = skin_elm.am_pm.as_ref() {
+            skin.add(Box::new(Self::load_am_pm_view(am_pm_elm, textures)?));
+        }
+
+        for elm in skin_elm.separators.as_slice() {
+            skin.add(Box::new(Self::load_separator_view(
+                elm,
+                textures,
+                &skin_elm.display.regions
+            )?));
+        }
+
+        for elm in skin_elm.world_clocks.as_slice() {
+            skin.add(Box::new(Self::load_world_clock_view(elm, textures)?));
+        }
 
         let rng = Rc::new(RefCell::new(Self::gen_chobit_rand()));
 
@@ -283,41 +872,138 @@ impl SettingsLoader {
 
         let depth = display_elm.depth;
 
-        Ok(DisplayView::new(
+        Self::load_display_regions(
             depth,
             texture_id,
-            match &display_elm.regions.centiseconds {
-                Some(number_region) =>
-                    Some(Self::load_display_number(&number_region)?),
-                None => None
-            },
-            match &display_elm.regions.seconds {
+            &display_elm.regions,
+            textures
+        )
+    }
+
+    fn load_display_regions(
+        depth: i32,
+        texture_id: TextureId,
+        regions: &DisplayRegions,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<DisplayView, Error> {
+        Ok(DisplayView::new(
+            depth,
+            texture_id,
+            Self::load_display_time(regions)?,
+            Self::load_display_date(regions, textures)?
+        ))
+    }
+
+    fn load_display_time(
+        regions: &DisplayRegions
+    ) -> Result<DisplayTime, Error> {
+        Ok(DisplayTime {
+            cents: match &regions.centiseconds {
+                Some(number_region) =>
+                    Some(Self::load_display_number(number_region)?),
+                None => None
+            },
+            seconds: match &regions.seconds {
                 Some(number_region) =>
-                    Some(Self::load_display_number(&number_region)?),
+                    Some(Self::load_display_number(number_region)?),
                 None => None
             },
-            match &display_elm.regions.minutes {
+            minutes: match &regions.minutes {
                 Some(number_region) =>
-                    Some(Self::load_display_number(&number_region)?),
+                    Some(Self::load_display_number(number_region)?),
                 None => None
             },
-            match &display_elm.regions.hours {
+            hours: match &regions.hours {
                 Some(number_region) =>
-                    Some(Self::load_display_number(&number_region)?),
+                    Some(Self::load_display_number(number_region)?),
+                None => None
+            },
+            days: match &regions.days {
+                Some(number_region) =>
+                    Some(Self::load_display_number(number_region)?),
                 None => None
             }
-        ))
+        })
+    }
+
+    fn load_display_date(
+        regions: &DisplayRegions,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<DisplayDate, Error> {
+        Ok(DisplayDate {
+            year: match &regions.year {
+                Some(number_region) =>
+                    Some(Self::load_display_number(number_region)?),
+                None => None
+            },
+            month: match &regions.month {
+                Some(number_region) =>
+                    Some(Self::load_display_number(number_region)?),
+                None => None
+            },
+            day: match &regions.day {
+                Some(number_region) =>
+                    Some(Self::load_display_number(number_region)?),
+                None => None
+            },
+            weekday: match &regions.weekday {
+                Some(weekday_elm) => {
+                    let key = fnv_1a_64(weekday_elm.texture_name.as_bytes());
+
+                    let texture_id = textures.get(key).ok_or_else(|| 
+                        LoadError::TextureNotFound(
+                            weekday_elm.texture_name.clone()
+                        )
+                    )?.id();
+
+                    Some(DisplayWeekday::new(
+                        texture_id,
+                        Self::region_to_rect(&weekday_elm.region)?
+                    ))
+                },
+
+                None => None
+            }
+        })
     }
 
     fn load_display_number(
         number_region: &NumberRegion
     ) -> Result<DisplayNumber, Error> {
         Ok(DisplayNumber::new(
-            Self::region_to_rect(&number_region.d1)?,
-            Self::region_to_rect(&number_region.d2)?
+            Self::load_digit_regions(number_region)?,
+            number_region.hide_leading_zeros,
+            number_region.hide_when_zero
         ))
     }
 
+    // `d1`, `d2`, `d3`, ... `dN`
+    fn load_digit_regions(
+        number_region: &NumberRegion
+    ) -> Result<Vec<Rect>, Error> {
+        let mut ret = Vec::<Rect>::with_capacity(
+            number_region.more_digits.len() + 2
+        );
+
+        ret.push(Self::region_to_rect(&number_region.d1)?);
+        ret.push(Self::region_to_rect(&number_region.d2)?);
+
+        while let Some(region) = number_region.more_digits.get(
+            &format!("d{}", ret.len() + 1)
+        ) {
+            ret.push(Self::region_to_rect(region)?);
+        }
+
+        // unknown names or missing digits.
+        if let Some(name) = number_region.more_digits.keys().find(
+            |name| !(3..=ret.len()).any(|index| **name == format!("d{}", index))
+        ) {
+            return Err(Error::from(LoadError::InvalidDigitName(name.clone())));
+        }
+
+        Ok(ret)
+    }
+
     fn load_switch_btn_view(
         switch_elm: &SwitchBtnElement,
         textures: &ChobitMap<TextureHandle>
@@ -330,13 +1016,38 @@ impl SettingsLoader {
 
         let depth = switch_elm.depth;
 
+        let has_timer_frame = match switch_elm.frames {
+            None | Some(2) => false,
+            Some(3) => true,
+            Some(frames) => {
+                return Err(Error::from(
+                    LoadError::InvalidSwitchBtnFrames(frames)
+                ));
+            }
+        };
+
         Ok(SwitchBtnView::new(
             depth,
             texture_id,
-            Self::region_to_rect(&switch_elm.region)?
+            Self::region_to_rect(&switch_elm.region)?,
+            Self::load_button_states(&switch_elm.states)?,
+            has_timer_frame
         ))
     }
 
+    // 1: idle
+    // 3: idle, hover, pressed
+    // 4: idle, hover, pressed, disabled
+    fn load_button_states(states: &Option<usize>) -> Result<usize, Error> {
+        match states {
+            None => Ok(1),
+            Some(states @ (1 | 3 | 4)) => Ok(*states),
+            Some(states) => {
+                Err(Error::from(LoadError::InvalidButtonStates(*states)))
+            }
+        }
+    }
+
     fn load_start_stop_btn_view(
         start_stop_elm: &StartStopBtnElement,
         textures: &ChobitMap<TextureHandle>
@@ -353,6 +1064,7 @@ impl SettingsLoader {
             depth,
             texture_id,
             Self::region_to_rect(&start_stop_elm.region)?,
+            Self::load_button_states(&start_stop_elm.states)?,
             Button::StartStop
         ))
     }
@@ -373,6 +1085,7 @@ impl SettingsLoader {
             depth,
             texture_id,
             Self::region_to_rect(&reset_elm.region)?,
+            Self::load_button_states(&reset_elm.states)?,
             Button::Reset
         ))
     }
@@ -393,10 +1106,227 @@ impl SettingsLoader {
             depth,
             texture_id,
             Self::region_to_rect(&quit_elm.region)?,
+            Self::load_button_states(&quit_elm.states)?,
             Button::Quit
         ))
     }
 
+    fn load_lap_btn_view(
+        lap_elm: &LapBtnElement,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<ButtonView, Error> {
+        let key = fnv_1a_64(lap_elm.texture_name.as_bytes());
+
+        let texture_id = textures.get(key).ok_or_else(|| 
+            LoadError::TextureNotFound(lap_elm.texture_name.clone())
+        )?.id();
+
+        let depth = lap_elm.depth;
+
+        Ok(ButtonView::new(
+            depth,
+            texture_id,
+            Self::region_to_rect(&lap_elm.region)?,
+            Self::load_button_states(&lap_elm.states)?,
+            Button::Lap
+        ))
+    }
+
+    fn load_btn_view(
+        button_elm: &ButtonElement,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<ButtonView, Error> {
+        let key = fnv_1a_64(button_elm.texture_name.as_bytes());
+
+        let texture_id = textures.get(key).ok_or_else(|| 
+            LoadError::TextureNotFound(button_elm.texture_name.clone())
+        )?.id();
+
+        let depth = button_elm.depth;
+
+        Ok(ButtonView::new(
+            depth,
+            texture_id,
+            Self::region_to_rect(&button_elm.region)?,
+            Self::load_button_states(&button_elm.states)?,
+            Self::load_button_action(
+                &button_elm.action,
+                &button_elm.skin_name
+            )?
+        ))
+    }
+
+    fn load_lap_view(
+        laps_elm: &LapsElement,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<LapView, Error> {
+        let key = fnv_1a_64(laps_elm.texture_name.as_bytes());
+
+        let texture_id = textures.get(key).ok_or_else(|| 
+            LoadError::TextureNotFound(laps_elm.texture_name.clone())
+        )?.id();
+
+        let depth = laps_elm.depth;
+
+        let mut rows = Vec::<LapRow>::with_capacity(laps_elm.rows.len());
+
+        for row_elm in laps_elm.rows.as_slice() {
+            rows.push(LapRow::new(
+                match &row_elm.number {
+                    Some(number_region) =>
+                        Some(Self::load_display_number(number_region)?),
+                    None => None
+                },
+                match &row_elm.split {
+                    Some(regions) => Some(
+                        Self::load_display_regions(
+                            depth,
+                            texture_id,
+                            regions,
+                            textures
+                        )?
+                    ),
+                    None => None
+                },
+                match &row_elm.lap {
+                    Some(regions) => Some(
+                        Self::load_display_regions(
+                            depth,
+                            texture_id,
+                            regions,
+                            textures
+                        )?
+                    ),
+                    None => None
+                }
+            ));
+        }
+
+        Ok(LapView::new(depth, texture_id, rows))
+    }
+
+    fn load_phase_view(
+        phase_elm: &PhaseElement,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<PhaseView, Error> {
+        let key = fnv_1a_64(phase_elm.texture_name.as_bytes());
+
+        let texture_id = textures.get(key).ok_or_else(|| 
+            LoadError::TextureNotFound(phase_elm.texture_name.clone())
+        )?.id();
+
+        Ok(PhaseView::new(
+            phase_elm.depth,
+            texture_id,
+            Self::load_display_number(&phase_elm.number)?
+        ))
+    }
+
+    fn load_am_pm_view(
+        am_pm_elm: &AmPmElement,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<AmPmView, Error> {
+        let key = fnv_1a_64(am_pm_elm.texture_name.as_bytes());
+
+        let texture_id = textures.get(key).ok_or_else(|| 
+            LoadError::TextureNotFound(am_pm_elm.texture_name.clone())
+        )?.id();
+
+        Ok(AmPmView::new(
+            am_pm_elm.depth,
+            texture_id,
+            Self::region_to_rect(&am_pm_elm.region)?
+        ))
+    }
+
+    // `regions` are of the display of the skin.
+    fn load_separator_view(
+        separator_elm: &SeparatorElement,
+        textures: &ChobitMap<TextureHandle>,
+        regions: &DisplayRegions
+    ) -> Result<SeparatorView, Error> {
+        let key = fnv_1a_64(separator_elm.texture_name.as_bytes());
+
+        let texture_id = textures.get(key).ok_or_else(|| 
+            LoadError::TextureNotFound(separator_elm.texture_name.clone())
+        )?.id();
+
+        // the field is hidden only if the display hides it.
+        let hidden_with = match separator_elm.field.as_ref() {
+            Some(name) => match Self::load_time_field(name, regions)? {
+                (field, Some(number_region))
+                    if number_region.hide_when_zero => Some(field),
+
+                _ => None
+            },
+
+            None => None
+        };
+
+        Ok(SeparatorView::new(
+            separator_elm.depth,
+            texture_id,
+            Self::region_to_rect(&separator_elm.region)?,
+            hidden_with,
+            regions.days.is_some()
+        ))
+    }
+
+    fn load_time_field<'a>(
+        name: &str,
+        regions: &'a DisplayRegions
+    ) -> Result<(TimeField, Option<&'a NumberRegion>), Error> {
+        match name {
+            "days" => Ok((TimeField::Days, regions.days.as_ref())),
+            "hours" => Ok((TimeField::Hours, regions.hours.as_ref())),
+            "minutes" => Ok((TimeField::Minutes, regions.minutes.as_ref())),
+            "seconds" => Ok((TimeField::Seconds, regions.seconds.as_ref())),
+            "centiseconds" =>
+                Ok((TimeField::Cents, regions.centiseconds.as_ref())),
+
+            _ => Err(Error::from(LoadError::InvalidTimeField(name.to_string())))
+        }
+    }
+
+    fn load_world_clock_view(
+        world_clock_elm: &WorldClockElement,
+        textures: &ChobitMap<TextureHandle>
+    ) -> Result<WorldClockView, Error> {
+        let key = fnv_1a_64(world_clock_elm.texture_name.as_bytes());
+
+        let texture_id = textures.get(key).ok_or_else(|| 
+            LoadError::TextureNotFound(world_clock_elm.texture_name.clone())
+        )?.id();
+
+        let depth = world_clock_elm.depth;
+
+        let label = match &world_clock_elm.label {
+            Some(label_elm) => {
+                let key = fnv_1a_64(label_elm.texture_name.as_bytes());
+
+                let texture_id = textures.get(key).ok_or_else(|| 
+                    LoadError::TextureNotFound(label_elm.texture_name.clone())
+                )?.id();
+
+                Some((texture_id, Self::region_to_rect(&label_elm.region)?))
+            },
+
+            None => None
+        };
+
+        Ok(WorldClockView::new(
+            depth,
+            Self::load_display_regions(
+                depth,
+                texture_id,
+                &world_clock_elm.regions,
+                textures
+            )?,
+            Self::load_time_zone(&world_clock_elm.zone)?,
+            label
+        ))
+    }
+
     #[inline]
     fn gen_chobit_rand() -> ChobitRand {
         let date: DateTime<Utc> = Utc::now();
@@ -426,6 +1356,7 @@ impl SettingsLoader {
                 anim_elm.fps
             )?,
             anim_elm.probability,
+            anim_elm.on_alarm,
             rng
         ))
     }
@@ -433,6 +1364,8 @@ impl SettingsLoader {
     pub fn load_default_mode(mode: &str) -> Result<WatchMode, Error> {
         if mode == "stopwatch" {
             Ok(WatchMode::Stopwatch(StopwatchMode::Stopped))
+        } else if mode == "timer" {
+            Ok(WatchMode::Timer(TimerMode::Stopped))
         } else if mode == "clock" {
             Ok(WatchMode::Clock)
         } else {
@@ -440,8 +1373,118 @@ impl SettingsLoader {
         }
     }
 
+    /// `"24h"` if it is omitted.
+    pub fn load_clock_format(
+        clock_format: &Option<String>
+    ) -> Result<ClockFormat, Error> {
+        match clock_format.as_deref() {
+            None | Some("24h") => Ok(ClockFormat::H24),
+            Some("12h") => Ok(ClockFormat::H12),
+            Some(format) => Err(Error::from(
+                LoadError::InvalidClockFormat(format.to_string())
+            ))
+        }
+    }
+
+    /// Local time zone of the system if it is omitted.
+    pub fn load_clock_zone(
+        clock_zone: &Option<String>
+    ) -> Result<ClockZone, Error> {
+        match clock_zone.as_deref() {
+            Some(zone) => Self::load_time_zone(zone),
+            None => Ok(ClockZone::Local)
+        }
+    }
+
+    pub fn load_time_zone(zone: &str) -> Result<ClockZone, Error> {
+        ClockZone::from_name(zone).ok_or_else(
+            || Error::from(LoadError::InvalidTimeZone(zone.to_string()))
+        )
+    }
+
+    pub fn load_key_bindings(
+        key_bindings: &Option<Vec<KeyBindingElement>>
+    ) -> Result<KeyBindings, Error> {
+        let key_bindings = match key_bindings {
+            Some(key_bindings) => key_bindings,
+            None => {return Ok(KeyBindings::default());}
+        };
+
+        let mut ret = Vec::<(Key, Button)>::with_capacity(key_bindings.len());
+
+        for elm in key_bindings.as_slice() {
+            let key = KeyBindings::find_key(&elm.key).ok_or_else(
+                || Error::from(LoadError::InvalidKeyName(elm.key.clone()))
+            )?;
+
+            ret.push((
+                key,
+                Self::load_button_action(&elm.action, &elm.skin_name)?
+            ));
+        }
+
+        Ok(KeyBindings::new(ret))
+    }
+
+    /// `skin_name` is used by "select_skin".
+    pub fn load_button_action(
+        action: &str,
+        skin_name: &Option<String>
+    ) -> Result<Button, Error> {
+        match action {
+            "switch" => Ok(Button::Switch),
+            "start_stop" => Ok(Button::StartStop),
+            "reset" => Ok(Button::Reset),
+            "lap" => Ok(Button::Lap),
+            "quit" => Ok(Button::Quit),
+            "next_skin" => Ok(Button::NextSkin),
+            "prev_skin" => Ok(Button::PrevSkin),
+            "clear_skin" => Ok(Button::ClearSkin),
+
+            "select_skin" => match skin_name {
+                Some(name) =>
+                    Ok(Button::SelectSkin(fnv_1a_64(name.as_bytes()))),
+
+                None => Err(Error::from(
+                    LoadError::SkinNameNotSpecified(String::from(action))
+                ))
+            },
+
+            "toggle_always_on_top" => Ok(Button::ToggleAlwaysOnTop),
+            "minimize" => Ok(Button::Minimize),
+            "dismiss_alarm" => Ok(Button::DismissAlarm),
+            "snooze_alarm" => Ok(Button::SnoozeAlarm),
+
+            _ => Err(Error::from(
+                LoadError::InvalidButtonAction(String::from(action))
+            ))
+        }
+    }
+
     pub fn load_stopwatch_events(
         events: &Vec<EventElement>
+    ) -> Result<Vec<SkinSwitchEvent>, Error> {
+        Self::load_events(events, LoadError::StopwatchEventError, false)
+    }
+
+    pub fn load_timer_events(
+        events: &Vec<EventElement>
+    ) -> Result<Vec<SkinSwitchEvent>, Error> {
+        Self::load_events(events, LoadError::TimerEventError, false)
+    }
+
+    pub fn load_clock_events(
+        events: &Vec<EventElement>
+    ) -> Result<Vec<SkinSwitchEvent>, Error> {
+        Self::load_events(events, LoadError::ClockEventError, true)
+    }
+
+    // only clock events are of clock time and have date conditions.
+    #[allow(clippy::redundant_field_names)]
+    fn load_events(
+        events: &Vec<EventElement>,
+        to_load_error: ToLoadError,
+        is_clock: bool
     ) -> Result<Vec<SkinSwitchEvent>, Error> {
         let mut ret = Vec::<SkinSwitchEvent>::with_capacity(
             events.len()
@@ -450,121 +1493,970 @@ impl SettingsLoader {
         for elm in events.as_slice() {
             let skin_id = fnv_1a_64(elm.skin_name.as_bytes());
 
-            let from = {
-                let cents = elm.from.centiseconds;
-                if cents > 99 {
-                    return Err(Error::from(
-                        LoadError::StopwatchEventError(
-                            EventError::Centiseconds(cents)
-                        )
-                    ));
-                }
-
-                let seconds = elm.from.seconds;
-                if seconds > 59 {
-                    return Err(Error::from(
-                        LoadError::StopwatchEventError(
-                            EventError::Seconds(seconds)
-                        )
-                    ));
-                }
+            let from = Self::load_time(&elm.from).map_err(
+                |error| Error::from(to_load_error(error))
+            )?;
 
-                let minutes = elm.from.minutes;
-                if minutes > 59 {
-                    return Err(Error::from(
-                        LoadError::StopwatchEventError(
-                            EventError::Minutes(minutes)
-                        )
-                    ));
-                }
+            if is_clock && from.hours > 23 {
+                return Err(Error::from(
+                    to_load_error(EventError::Hours(from.hours))
+                ));
+            }
 
-                let hours = elm.from.hours;
-                if hours > 99 {
-                    return Err(Error::from(
-                        LoadError::StopwatchEventError(
-                            EventError::Hours(hours)
-                        )
-                    ));
-                }
+            let date_condition = Self::load_date_condition(elm).map_err(
+                |error| Error::from(to_load_error(error))
+            )?;
 
-                WatchTime {
-                    cents: cents,
-                    seconds: seconds,
-                    minutes: minutes,
-                    hours: hours
-                }
-            };
+            if !is_clock && !date_condition.is_empty() {
+                return Err(Error::from(
+                    to_load_error(EventError::DateCondition)
+                ));
+            }
 
             ret.push(SkinSwitchEvent {
                 skin_id: skin_id,
-                from_time: from
+                from_time: from,
+                date_condition: date_condition
             })
         }
 
         Ok(ret)
     }
 
-    pub fn load_clock_events(
-        events: &Vec<EventElement>
-    ) -> Result<Vec<SkinSwitchEvent>, Error> {
-        let mut ret = Vec::<SkinSwitchEvent>::with_capacity(
-            events.len()
-        );
+    #[allow(clippy::redundant_field_names)]
+    fn load_date_condition(
+        elm: &EventElement
+    ) -> Result<DateCondition, EventError> {
+        let weekdays = Self::load_weekdays(&elm.weekdays)?;
 
-        for elm in events.as_slice() {
-            let skin_id = fnv_1a_64(elm.skin_name.as_bytes());
+        let date_range = match (elm.date_from.as_ref(), elm.date_to.as_ref()) {
+            (Some(from), Some(to)) => {
+                let from = Self::load_date(from)?;
+                let to = Self::load_date(to)?;
 
-            let from = {
-                let cents = elm.from.centiseconds;
-                if cents > 99 {
-                    return Err(Error::from(
-                        LoadError::ClockEventError(
-                            EventError::Centiseconds(cents)
-                        )
-                    ));
+                if from.year.is_some() != to.year.is_some() {
+                    return Err(EventError::DateRange);
                 }
 
-                let seconds = elm.from.seconds;
-                if seconds > 59 {
-                    return Err(Error::from(
-                        LoadError::ClockEventError(
-                            EventError::Seconds(seconds)
-                        )
-                    ));
-                }
+                Some((from, to))
+            },
 
-                let minutes = elm.from.minutes;
-                if minutes > 59 {
-                    return Err(Error::from(
-                        LoadError::ClockEventError(
-                            EventError::Minutes(minutes)
-                        )
-                    ));
-                }
+            (None, None) => None,
 
-                let hours = elm.from.hours;
-                if hours > 99 {
-                    return Err(Error::from(
-                        LoadError::ClockEventError(
-                            EventError::Hours(hours)
-                        )
-                    ));
-                }
+            _ => {return Err(EventError::DateRange);}
+        };
 
-                WatchTime {
-                    cents: cents,
-                    seconds: seconds,
-                    minutes: minutes,
-                    hours: hours
-                }
+        let mut dates = Vec::<EventDate>::with_capacity(elm.dates.len());
+
+        for date in elm.dates.iter() {
+            dates.push(Self::load_date(date)?);
+        }
+
+        Ok(DateCondition {
+            weekdays: weekdays,
+            date_range: date_range,
+            dates: dates
+        })
+    }
+
+    fn load_weekdays(weekdays: &[String]) -> Result<Vec<Weekday>, EventError> {
+        let mut ret = Vec::<Weekday>::with_capacity(weekdays.len());
+
+        for weekday in weekdays.iter() {
+            ret.push(weekday.parse::<Weekday>().map_err(
+                |_| EventError::Weekday(weekday.clone())
+            )?);
+        }
+
+        Ok(ret)
+    }
+
+    pub fn load_date(date_elm: &DateElement) -> Result<EventDate, EventError> {
+        // a leap year accepts February 29 of every year.
+        let year = date_elm.year.unwrap_or(2000);
+
+        match NaiveDate::from_ymd_opt(year, date_elm.month, date_elm.day) {
+            Some(..) => Ok(EventDate {
+                year: date_elm.year,
+                month: date_elm.month,
+                day: date_elm.day
+            }),
+
+            None => Err(EventError::Date {
+                year: date_elm.year,
+                month: date_elm.month,
+                day: date_elm.day
+            })
+        }
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    pub fn load_time(time_elm: &TimeElement) -> Result<WatchTime, EventError> {
+        let cents = time_elm.centiseconds;
+        if cents > 99 {
+            return Err(EventError::Centiseconds(cents));
+        }
+
+        let seconds = time_elm.seconds;
+        if seconds > 59 {
+            return Err(EventError::Seconds(seconds));
+        }
+
+        let minutes = time_elm.minutes;
+        if minutes > 59 {
+            return Err(EventError::Minutes(minutes));
+        }
+
+        let hours = time_elm.hours;
+
+        Ok(WatchTime {
+            cents: cents,
+            seconds: seconds,
+            minutes: minutes,
+            hours: hours
+        })
+    }
+
+    pub fn load_timer_duration(
+        duration: &Option<TimeElement>
+    ) -> Result<Option<WatchTime>, Error> {
+        match duration {
+            Some(time_elm) => Ok(Some(
+                Self::load_time(time_elm).map_err(
+                    |error| Error::from(LoadError::TimerDurationError(error))
+                )?
+            )),
+
+            None => Ok(None)
+        }
+    }
+
+    /// 100 hours if it is omitted.
+    pub fn load_stopwatch_limit(
+        limit: &Option<TimeElement>
+    ) -> Result<WatchTime, Error> {
+        let limit = match limit {
+            Some(time_elm) => Self::load_time(time_elm).map_err(
+                |error| Error::from(LoadError::StopwatchLimitError(error))
+            )?,
+
+            None => {return Ok(DEFAULT_STOPWATCH_LIMIT);}
+        };
+
+        if limit == WatchTime::default() {
+            return Err(Error::from(LoadError::ZeroStopwatchLimit));
+        }
+
+        Ok(limit)
+    }
+
+    /// Reports skins whose display can't show the stopwatch up to `limit`.
+    ///
+    /// These are not errors. The display just shows the lower digits.
+    #[allow(clippy::redundant_field_names)]
+    pub fn check_stopwatch_limit(
+        skins: &[SkinElement],
+        limit: &WatchTime,
+        warnings: &mut LoadProblems
+    ) {
+        for (index, elm) in skins.iter().enumerate() {
+            let max_hours = match Self::max_display_hours(
+                &elm.display.regions
+            ) {
+                Some(max_hours) => max_hours,
+                None => {continue;}
             };
 
-            ret.push(SkinSwitchEvent {
-                skin_id: skin_id,
-                from_time: from
+            if limit.to_cents() > max_hours.saturating_mul(360_000) {
+                warnings.push(
+                    &format!("skins[{}] ({:?})", index, elm.name),
+                    Error::from(LoadError::StopwatchLimitNotShown {
+                        max_hours: max_hours
+                    })
+                );
+            }
+        }
+    }
+
+    /// Hours which the display can show without dropping digits.
+    ///
+    /// `None` if the display has neither hours nor days.
+    pub fn max_display_hours(regions: &DisplayRegions) -> Option<u64> {
+        let digits = |number_region: &NumberRegion| {
+            10u64.saturating_pow(number_region.more_digits.len() as u32 + 2)
+        };
+
+        match (regions.days.as_ref(), regions.hours.as_ref()) {
+            (Some(days), _) => Some(digits(days).saturating_mul(24)),
+            (None, Some(hours)) => Some(digits(hours)),
+            (None, None) => None
+        }
+    }
+
+    /// `"wrap"` if it is omitted.
+    pub fn load_stopwatch_overflow(
+        overflow: &Option<String>
+    ) -> Result<OverflowPolicy, Error> {
+        match overflow.as_deref() {
+            None | Some("wrap") => Ok(OverflowPolicy::Wrap),
+            Some("saturate") => Ok(OverflowPolicy::Saturate),
+            Some("stop") => Ok(OverflowPolicy::Stop),
+            Some(overflow) => Err(Error::from(
+                LoadError::InvalidStopwatchOverflow(overflow.to_string())
+            ))
+        }
+    }
+
+    /// Returns the timer duration and phases of the interval timer.
+    ///
+    /// If `timer_phases` is specified, the duration is of the first phase.
+    #[allow(clippy::redundant_field_names)]
+    pub fn load_timer_settings(
+        settings: &Settings
+    ) -> Result<(Option<WatchTime>, Vec<TimerPhase>), Error> {
+        let timer_duration =
+            Self::load_timer_duration(&settings.timer_duration)?;
+
+        if settings.timer_phases.is_empty() {
+            return Ok((timer_duration, Vec::<TimerPhase>::new()));
+        }
+
+        if timer_duration.is_some() {
+            return Err(Error::from(LoadError::TimerDurationAndPhases));
+        }
+
+        let mut phases =
+            Vec::<TimerPhase>::with_capacity(settings.timer_phases.len());
+
+        for (index, elm) in settings.timer_phases.iter().enumerate() {
+            let duration = Self::load_time(&elm.duration).map_err(
+                |error| Error::from(
+                    LoadError::TimerPhaseError {index: index, error: error}
+                )
+            )?;
+
+            if duration == WatchTime::default() {
+                return Err(Error::from(LoadError::ZeroTimerPhase(index)));
+            }
+
+            phases.push(TimerPhase {
+                duration: duration,
+                skin_id: elm.skin_name.as_ref().map(
+                    |name| fnv_1a_64(name.as_bytes())
+                )
+            });
+        }
+
+        Ok((Some(phases[0].duration.clone()), phases))
+    }
+
+    /// `timer_duration` is the result of `load_timer_settings()`.
+    /// Default timer skin is required only if timer is enabled.
+    #[allow(clippy::redundant_field_names)]
+    pub fn load_default_skin_ids(
+        settings: &Settings,
+        timer_duration: &Option<WatchTime>
+    ) -> Result<DefaultSkinIds, Error> {
+        let timer = match (timer_duration, &settings.default_timer_skin_name) {
+            (_, Some(name)) => fnv_1a_64(name.as_bytes()),
+            (None, None) => Default::default(),
+            (Some(..), None) => {
+                return Err(Error::from(LoadError::TimerSkinNotSpecified));
+            }
+        };
+
+        Ok(DefaultSkinIds {
+            stopwatch: fnv_1a_64(
+                settings.default_stopwatch_skin_name.as_bytes()
+            ),
+            timer: timer,
+            clock: fnv_1a_64(settings.default_clock_skin_name.as_bytes())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    const EXAMPLE: &str = include_str!("../examples/example_settings.yaml");
+
+    #[test]
+    fn load_default_skin_ids_test() {
+        let mut settings: Settings = serde_yaml::from_str(EXAMPLE).unwrap();
+
+        settings.default_stopwatch_skin_name = "skin_1".to_string();
+        settings.default_timer_skin_name = Some("skin_2".to_string());
+        settings.default_clock_skin_name = "skin_3".to_string();
+
+        assert_eq!(
+            SettingsLoader::load_default_skin_ids(&settings, &None).unwrap(),
+            DefaultSkinIds {
+                stopwatch: fnv_1a_64(b"skin_1"),
+                timer: fnv_1a_64(b"skin_2"),
+                clock: fnv_1a_64(b"skin_3")
+            }
+        );
+
+        let timer_duration = Some(WatchTime {
+            hours: 0,
+            minutes: 3,
+            seconds: 0,
+            cents: 0
+        });
+
+        assert_eq!(
+            SettingsLoader::load_default_skin_ids(
+                &settings,
+                &timer_duration
+            ).unwrap().timer,
+            fnv_1a_64(b"skin_2")
+        );
+
+        settings.default_timer_skin_name = None;
+
+        assert_eq!(
+            SettingsLoader::load_default_skin_ids(&settings, &None).unwrap()
+                .timer,
+            u64::default()
+        );
+
+        assert!(
+            SettingsLoader::load_default_skin_ids(
+                &settings,
+                &timer_duration
+            ).is_err()
+        );
+    }
+
+    #[test]
+    fn find_unknown_skin_names_test() {
+        let mut settings: Settings = serde_yaml::from_str(EXAMPLE).unwrap();
+
+        let mut skin_names = ChobitMap::<String>::new(TABLE_SIZE);
+
+        for elm in settings.skins.iter() {
+            skin_names.add(fnv_1a_64(elm.name.as_bytes()), elm.name.clone())
+                .unwrap();
+        }
+
+        assert!(
+            SettingsLoader::find_unknown_skin_names(&settings, &skin_names)
+                .is_empty()
+        );
+
+        settings.default_clock_skin_name = "skin_black".to_string();
+        settings.clock_events[1].skin_name = "skin_green".to_string();
+
+        assert_eq!(
+            SettingsLoader::find_unknown_skin_names(&settings, &skin_names),
+            vec![
+                LoadError::SkinNotFound {
+                    key: "default_clock_skin_name".to_string(),
+                    name: "skin_black".to_string()
+                },
+                LoadError::ClockEventError(EventError::SkinNotFound {
+                    index: 1,
+                    name: "skin_green".to_string()
+                })
+            ]
+        );
+    }
+
+    #[test]
+    fn load_button_states_test() {
+        assert_eq!(SettingsLoader::load_button_states(&None).unwrap(), 1);
+
+        for states in [1, 3, 4] {
+            assert_eq!(
+                SettingsLoader::load_button_states(&Some(states)).unwrap(),
+                states
+            );
+        }
+
+        for states in [0, 2, 5] {
+            assert!(matches!(
+                SettingsLoader::load_button_states(&Some(states)),
+                Err(Error::LoadError(LoadError::InvalidButtonStates(n)))
+                    if n == states
+            ));
+        }
+    }
+
+    #[test]
+    fn load_key_bindings_test() {
+        assert_eq!(
+            SettingsLoader::load_key_bindings(&None).unwrap(),
+            KeyBindings::default()
+        );
+
+        // an empty array disables every key.
+        assert_eq!(
+            SettingsLoader::load_key_bindings(&Some(vec![])).unwrap(),
+            KeyBindings::new(vec![])
+        );
+
+        let gen_elm = |key: &str, action: &str| KeyBindingElement {
+            key: key.to_string(),
+            action: action.to_string(),
+            skin_name: None
+        };
+
+        let mut select_skin = gen_elm("f1", "select_skin");
+        select_skin.skin_name = Some("skin_red".to_string());
+
+        assert_eq!(
+            SettingsLoader::load_key_bindings(&Some(vec![
+                gen_elm("Space", "lap"),
+                select_skin
+            ])).unwrap(),
+            KeyBindings::new(vec![
+                (Key::Space, Button::Lap),
+                (Key::F1, Button::SelectSkin(fnv_1a_64(b"skin_red")))
+            ])
+        );
+
+        assert!(matches!(
+            SettingsLoader::load_key_bindings(&Some(vec![
+                gen_elm("Space", "lap"),
+                gen_elm("Esc", "quit")
+            ])),
+            Err(Error::LoadError(LoadError::InvalidKeyName(name)))
+                if name == "Esc"
+        ));
+    }
+
+    #[test]
+    fn load_events_date_condition_test() {
+        let mut settings: Settings = serde_yaml::from_str(EXAMPLE).unwrap();
+
+        settings.stopwatch_events[0].weekdays = vec!["sat".to_string()];
+        settings.clock_events[0].weekdays = vec!["sat".to_string()];
+
+        assert!(matches!(
+            SettingsLoader::load_stopwatch_events(&settings.stopwatch_events),
+            Err(Error::LoadError(LoadError::StopwatchEventError(
+                EventError::DateCondition
+            )))
+        ));
+
+        assert!(matches!(
+            SettingsLoader::load_timer_events(&settings.stopwatch_events),
+            Err(Error::LoadError(LoadError::TimerEventError(
+                EventError::DateCondition
+            )))
+        ));
+
+        assert_eq!(
+            SettingsLoader::load_clock_events(&settings.clock_events)
+                .unwrap()[0].date_condition.weekdays,
+            vec![Weekday::Sat]
+        );
+
+        settings.stopwatch_events[0].weekdays.clear();
+        settings.stopwatch_events[0].dates =
+            vec![DateElement {year: None, month: 1, day: 1}];
+
+        assert!(matches!(
+            SettingsLoader::load_stopwatch_events(&settings.stopwatch_events),
+            Err(Error::LoadError(LoadError::StopwatchEventError(
+                EventError::DateCondition
+            )))
+        ));
+    }
+
+    #[test]
+    fn load_events_hours_test() {
+        let mut settings: Settings = serde_yaml::from_str(EXAMPLE).unwrap();
+
+        settings.stopwatch_events[0].from.hours = 24;
+        settings.clock_events[0].from.hours = 23;
+
+        assert_eq!(
+            SettingsLoader::load_stopwatch_events(&settings.stopwatch_events)
+                .unwrap()[0].from_time.hours,
+            24
+        );
+        assert!(
+            SettingsLoader::load_clock_events(&settings.clock_events).is_ok()
+        );
+
+        // clock events are of clock time.
+        settings.clock_events[0].from.hours = 24;
+
+        assert!(matches!(
+            SettingsLoader::load_clock_events(&settings.clock_events),
+            Err(Error::LoadError(LoadError::ClockEventError(
+                EventError::Hours(24)
+            )))
+        ));
+    }
+
+    #[test]
+    fn load_date_condition_test() {
+        let mut settings: Settings = serde_yaml::from_str(EXAMPLE).unwrap();
+        let mut elm = settings.clock_events.remove(0);
+
+        elm.weekdays = vec!["sat".to_string(), "Sunday".to_string()];
+        elm.date_from = Some(DateElement {year: None, month: 12, day: 20});
+        elm.date_to = Some(DateElement {year: None, month: 1, day: 5});
+        elm.dates = vec![DateElement {year: None, month: 2, day: 29}];
+
+        assert_eq!(
+            SettingsLoader::load_date_condition(&elm),
+            Ok(DateCondition {
+                weekdays: vec![Weekday::Sat, Weekday::Sun],
+                date_range: Some((
+                    EventDate {year: None, month: 12, day: 20},
+                    EventDate {year: None, month: 1, day: 5}
+                )),
+                dates: vec![EventDate {year: None, month: 2, day: 29}]
             })
+        );
+
+        let mut elm_2 = elm.clone();
+        elm_2.weekdays = vec!["holiday".to_string()];
+        assert_eq!(
+            SettingsLoader::load_date_condition(&elm_2),
+            Err(EventError::Weekday("holiday".to_string()))
+        );
+
+        let mut elm_2 = elm.clone();
+        elm_2.date_to = None;
+        assert_eq!(
+            SettingsLoader::load_date_condition(&elm_2),
+            Err(EventError::DateRange)
+        );
+
+        let mut elm_2 = elm.clone();
+        elm_2.date_to = Some(DateElement {year: Some(2024), month: 1, day: 5});
+        assert_eq!(
+            SettingsLoader::load_date_condition(&elm_2),
+            Err(EventError::DateRange)
+        );
+
+        let mut elm_2 = elm.clone();
+        elm_2.dates = vec![DateElement {year: Some(2023), month: 2, day: 29}];
+        assert_eq!(
+            SettingsLoader::load_date_condition(&elm_2),
+            Err(EventError::Date {year: Some(2023), month: 2, day: 29})
+        );
+    }
+
+    #[test]
+    fn load_autosave_interval_test() {
+        assert_eq!(
+            SettingsLoader::load_autosave_interval(&None).unwrap(),
+            None
+        );
+        assert_eq!(
+            SettingsLoader::load_autosave_interval(&Some(1.5)).unwrap(),
+            Some(Duration::from_millis(1500))
+        );
+
+        for interval in [0.0, -1.0, f32::NAN, f32::INFINITY, 1e30] {
+            assert!(matches!(
+                SettingsLoader::load_autosave_interval(&Some(interval)),
+                Err(Error::LoadError(LoadError::InvalidAutosaveInterval(_)))
+            ));
         }
+    }
 
-        Ok(ret)
+    #[test]
+    fn load_alarms_test() {
+        let alarm_elm = AlarmElement {
+            time: TimeElement {
+                centiseconds: 0,
+                seconds: 0,
+                minutes: 30,
+                hours: 7
+            },
+            weekdays: vec!["mon".to_string()],
+            skin_name: Some("skin_1".to_string()),
+            sound: Some("alarm.wav".to_string())
+        };
+
+        assert_eq!(
+            SettingsLoader::load_alarms(
+                &Path::new("root"),
+                std::slice::from_ref(&alarm_elm)
+            ).unwrap(),
+            vec![Alarm {
+                time: WatchTime {
+                    hours: 7,
+                    minutes: 30,
+                    seconds: 0,
+                    cents: 0
+                },
+                weekdays: vec![Weekday::Mon],
+                skin_id: Some(fnv_1a_64(b"skin_1")),
+                sound: Some(Path::new("root").join("alarm.wav"))
+            }]
+        );
+
+        let mut alarm_elm_2 = alarm_elm.clone();
+        alarm_elm_2.time.hours = 24;
+        assert!(matches!(
+            SettingsLoader::load_alarms(
+                &Path::new("root"),
+                &[alarm_elm.clone(), alarm_elm_2]
+            ),
+            Err(Error::LoadError(LoadError::AlarmError {
+                index: 1,
+                error: EventError::Hours(24)
+            }))
+        ));
+
+        assert_eq!(
+            SettingsLoader::load_alarm_snooze(&None).unwrap(),
+            Duration::from_secs(300)
+        );
+        for snooze in [0.0, -1.0, f32::NAN, 1e30] {
+            assert!(matches!(
+                SettingsLoader::load_alarm_snooze(&Some(snooze)),
+                Err(Error::LoadError(LoadError::InvalidAlarmSnooze(_)))
+            ));
+        }
+
+        assert!(
+            SettingsLoader::load_alarm_sound_command(
+                &None,
+                std::slice::from_ref(&alarm_elm)
+            ).is_err()
+        );
+        assert_eq!(
+            SettingsLoader::load_alarm_sound_command(
+                &Some(vec!["aplay".to_string()]),
+                &[alarm_elm]
+            ).unwrap(),
+            vec!["aplay".to_string()]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::redundant_field_names)]
+    fn load_timer_settings_test() {
+        let mut settings: Settings = serde_yaml::from_str(EXAMPLE).unwrap();
+
+        let phase_elm = |minutes: u32, skin_name: Option<&str>| {
+            TimerPhaseElement {
+                duration: TimeElement {
+                    centiseconds: 0,
+                    seconds: 0,
+                    minutes: minutes,
+                    hours: 0
+                },
+                skin_name: skin_name.map(str::to_string)
+            }
+        };
+
+        let duration = |minutes: u32| WatchTime {
+            hours: 0,
+            minutes: minutes,
+            seconds: 0,
+            cents: 0
+        };
+
+        settings.timer_duration = None;
+        settings.timer_phases = vec![
+            phase_elm(25, Some("skin_1")),
+            phase_elm(5, None)
+        ];
+
+        let (timer_duration, phases) =
+            SettingsLoader::load_timer_settings(&settings).unwrap();
+
+        assert_eq!(timer_duration, Some(duration(25)));
+        assert_eq!(phases, vec![
+            TimerPhase {
+                duration: duration(25),
+                skin_id: Some(fnv_1a_64(b"skin_1"))
+            },
+            TimerPhase {
+                duration: duration(5),
+                skin_id: None
+            }
+        ]);
+
+        settings.timer_phases[1] = phase_elm(0, None);
+        assert!(matches!(
+            SettingsLoader::load_timer_settings(&settings),
+            Err(Error::LoadError(LoadError::ZeroTimerPhase(1)))
+        ));
+
+        settings.timer_phases[1] = phase_elm(60, None);
+        assert!(matches!(
+            SettingsLoader::load_timer_settings(&settings),
+            Err(Error::LoadError(LoadError::TimerPhaseError {
+                index: 1,
+                error: EventError::Minutes(60)
+            }))
+        ));
+
+        settings.timer_phases[1] = phase_elm(5, None);
+        settings.timer_duration = Some(phase_elm(1, None).duration);
+        assert!(matches!(
+            SettingsLoader::load_timer_settings(&settings),
+            Err(Error::LoadError(LoadError::TimerDurationAndPhases))
+        ));
+    }
+
+    #[test]
+    fn load_clock_format_test() {
+        assert_eq!(
+            SettingsLoader::load_clock_format(&None).unwrap(),
+            ClockFormat::H24
+        );
+        assert_eq!(
+            SettingsLoader::load_clock_format(
+                &Some("24h".to_string())
+            ).unwrap(),
+            ClockFormat::H24
+        );
+        assert_eq!(
+            SettingsLoader::load_clock_format(
+                &Some("12h".to_string())
+            ).unwrap(),
+            ClockFormat::H12
+        );
+        assert!(
+            SettingsLoader::load_clock_format(&Some("12".to_string())).is_err()
+        );
+    }
+
+    #[test]
+    fn load_clock_zone_test() {
+        assert_eq!(
+            SettingsLoader::load_clock_zone(&None).unwrap(),
+            ClockZone::Local
+        );
+        assert_eq!(
+            SettingsLoader::load_clock_zone(
+                &Some("-03:30".to_string())
+            ).unwrap(),
+            ClockZone::Fixed(-((3 * 60) + 30) * 60)
+        );
+        assert!(matches!(
+            SettingsLoader::load_time_zone("Not/A_Zone"),
+            Err(Error::LoadError(LoadError::InvalidTimeZone(zone)))
+                if zone == "Not/A_Zone"
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::redundant_field_names)]
+    fn load_digit_regions_test() {
+        let region = |x: f32| Region {
+            x: x,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0
+        };
+
+        let mut number_region = NumberRegion {
+            d1: region(1.0),
+            d2: region(2.0),
+            hide_leading_zeros: false,
+            hide_when_zero: false,
+            more_digits: BTreeMap::<String, Region>::new()
+        };
+
+        assert_eq!(
+            SettingsLoader::load_digit_regions(&number_region).unwrap().len(),
+            2
+        );
+
+        number_region.more_digits.insert("d4".to_string(), region(4.0));
+        number_region.more_digits.insert("d3".to_string(), region(3.0));
+
+        let rects = SettingsLoader::load_digit_regions(&number_region).unwrap();
+        assert_eq!(
+            rects.iter().map(|rect| rect.min.x).collect::<Vec<f32>>(),
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+
+        number_region.more_digits.insert("d6".to_string(), region(6.0));
+        assert!(matches!(
+            SettingsLoader::load_digit_regions(&number_region),
+            Err(Error::LoadError(LoadError::InvalidDigitName(name)))
+                if name == "d6"
+        ));
+    }
+
+    #[test]
+    fn load_display_date_test() {
+        let ctx = Context::default();
+
+        let mut textures = ChobitMap::<TextureHandle>::new(TABLE_SIZE);
+        textures.add(
+            fnv_1a_64(b"weekdays"),
+            ctx.load_texture(
+                "weekdays",
+                ColorImage::new([7, 1], Default::default()),
+                Default::default()
+            )
+        ).unwrap();
+
+        let mut regions: DisplayRegions = serde_yaml::from_str(
+            "{day: {d1: {x: 0, y: 0, width: 1, height: 1}, \
+                d2: {x: 1, y: 0, width: 1, height: 1}}, \
+            weekday: {texture_name: weekdays, \
+                region: {x: 2, y: 0, width: 1, height: 1}}}"
+        ).unwrap();
+
+        let date = SettingsLoader::load_display_date(&regions, &textures)
+            .unwrap();
+
+        assert!(date.year.is_none());
+        assert!(date.month.is_none());
+        assert!(date.day.is_some());
+        assert!(date.weekday.is_some());
+
+        regions.weekday.as_mut().unwrap().texture_name =
+            "not_found".to_string();
+
+        assert!(matches!(
+            SettingsLoader::load_display_date(&regions, &textures),
+            Err(Error::LoadError(LoadError::TextureNotFound(name)))
+                if name == "not_found"
+        ));
+    }
+
+    #[test]
+    fn load_time_field_test() {
+        let regions: DisplayRegions = serde_yaml::from_str(
+            "hours: {d1: {x: 0, y: 0, width: 1, height: 1}, \
+                d2: {x: 1, y: 0, width: 1, height: 1}}"
+        ).unwrap();
+
+        assert!(matches!(
+            SettingsLoader::load_time_field("hours", &regions),
+            Ok((TimeField::Hours, Some(..)))
+        ));
+        assert!(matches!(
+            SettingsLoader::load_time_field("centiseconds", &regions),
+            Ok((TimeField::Cents, None))
+        ));
+        assert!(matches!(
+            SettingsLoader::load_time_field("hour", &regions),
+            Err(Error::LoadError(LoadError::InvalidTimeField(field)))
+                if field == "hour"
+        ));
+    }
+
+    #[test]
+    fn check_stopwatch_limit_test() {
+        let mut settings: Settings = serde_yaml::from_str(EXAMPLE).unwrap();
+
+        // every skin of the example shows 2 digits of hours.
+        let regions = &settings.skins[0].display.regions;
+        assert_eq!(SettingsLoader::max_display_hours(regions), Some(100));
+
+        let mut warnings = LoadProblems::new();
+        SettingsLoader::check_stopwatch_limit(
+            &settings.skins,
+            &DEFAULT_STOPWATCH_LIMIT,
+            &mut warnings
+        );
+        assert!(warnings.is_empty());
+
+        let limit = WatchTime {
+            hours: 100,
+            minutes: 0,
+            seconds: 0,
+            cents: 1
+        };
+
+        let mut warnings = LoadProblems::new();
+        SettingsLoader::check_stopwatch_limit(
+            &settings.skins,
+            &limit,
+            &mut warnings
+        );
+
+        let warnings = warnings.into_vec();
+        assert_eq!(warnings.len(), settings.skins.len());
+        assert!(warnings[0].starts_with("skins[0]"));
+        assert!(warnings[0].contains(r#""max_hours": 100"#));
+
+        // a third digit of hours.
+        let mut regions = settings.skins[0].display.regions.clone();
+        let d2 = regions.hours.as_ref().unwrap().d2.clone();
+        regions.hours.as_mut().unwrap().more_digits.insert(
+            "d3".to_string(),
+            d2
+        );
+        assert_eq!(SettingsLoader::max_display_hours(&regions), Some(1000));
+
+        // days with 2 digits.
+        regions.days = regions.hours.clone();
+        regions.days.as_mut().unwrap().more_digits.clear();
+        assert_eq!(
+            SettingsLoader::max_display_hours(&regions),
+            Some(100 * 24)
+        );
+
+        regions.days = None;
+        regions.hours = None;
+        assert_eq!(SettingsLoader::max_display_hours(&regions), None);
+
+        settings.skins[0].display.regions = regions;
+
+        let mut warnings = LoadProblems::new();
+        SettingsLoader::check_stopwatch_limit(
+            &settings.skins,
+            &limit,
+            &mut warnings
+        );
+        assert_eq!(warnings.into_vec().len(), settings.skins.len() - 1);
+    }
+
+    #[test]
+    fn load_stopwatch_limit_test() {
+        assert_eq!(
+            SettingsLoader::load_stopwatch_limit(&None).unwrap(),
+            DEFAULT_STOPWATCH_LIMIT
+        );
+
+        let mut limit = TimeElement {
+            centiseconds: 0,
+            seconds: 0,
+            minutes: 30,
+            hours: 9999
+        };
+
+        assert_eq!(
+            SettingsLoader::load_stopwatch_limit(&Some(limit.clone())).unwrap(),
+            WatchTime {
+                hours: 9999,
+                minutes: 30,
+                seconds: 0,
+                cents: 0
+            }
+        );
+
+        limit.hours = 0;
+        limit.minutes = 0;
+        assert!(matches!(
+            SettingsLoader::load_stopwatch_limit(&Some(limit)),
+            Err(Error::LoadError(LoadError::ZeroStopwatchLimit))
+        ));
+
+        assert_eq!(
+            SettingsLoader::load_stopwatch_overflow(&None).unwrap(),
+            OverflowPolicy::Wrap
+        );
+        assert_eq!(
+            SettingsLoader::load_stopwatch_overflow(
+                &Some("saturate".to_string())
+            ).unwrap(),
+            OverflowPolicy::Saturate
+        );
+        assert!(
+            SettingsLoader::load_stopwatch_overflow(
+                &Some("clamp".to_string())
+            ).is_err()
+        );
     }
 }
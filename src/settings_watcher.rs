@@ -0,0 +1,151 @@
+use crate::settings::Settings;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime}
+};
+
+pub const CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches modified times of settings file and textures. (`--watch`)
+///
+/// Files are polled every `CHECK_INTERVAL`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+    last_check: Instant
+}
+
+impl SettingsWatcher {
+    pub fn new(
+        settings_file_name: &str,
+        settings: &Settings,
+        now: Instant
+    ) -> Self {
+        let mut ret = Self {
+            files: Vec::<(PathBuf, Option<SystemTime>)>::new(),
+            last_check: now
+        };
+
+        ret.set_files(settings_file_name, settings);
+
+        ret
+    }
+
+    /// Replaces watched textures with ones of `settings`.
+    pub fn set_files(&mut self, settings_file_name: &str, settings: &Settings) {
+        let settings_file = PathBuf::from(settings_file_name);
+
+        let root_dir = settings_file.parent().map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        self.files.clear();
+
+        self.files.push(Self::gen_file(settings_file));
+
+        for elm in settings.textures.iter() {
+            self.files.push(Self::gen_file(root_dir.join(&elm.path)));
+        }
+    }
+
+    /// Returns true if any file has been modified since the last check.
+    ///
+    /// Modified times are updated, so a change is reported only once.
+    pub fn check(&mut self, now: Instant) -> bool {
+        if now.saturating_duration_since(self.last_check) < CHECK_INTERVAL {
+            return false;
+        }
+
+        self.last_check = now;
+
+        let mut ret = false;
+
+        for (path, modified) in self.files.iter_mut() {
+            let current = Self::get_modified(path);
+
+            if current != *modified {
+                *modified = current;
+                ret = true;
+            }
+        }
+
+        ret
+    }
+
+    #[inline]
+    fn gen_file(path: PathBuf) -> (PathBuf, Option<SystemTime>) {
+        let modified = Self::get_modified(&path);
+
+        (path, modified)
+    }
+
+    // None if the file doesn't exist.
+    #[inline]
+    fn get_modified(path: &Path) -> Option<SystemTime> {
+        fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::TextureElement;
+    use std::fs::File;
+
+    #[test]
+    fn check_test() {
+        let dir = std::env::temp_dir().join(format!(
+            "anipwatch_settings_watcher_test_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let settings_file = dir.join("settings.yaml");
+        let texture_file = dir.join("texture.png");
+
+        fs::write(&settings_file, "").unwrap();
+        fs::write(&texture_file, "").unwrap();
+
+        let mut settings: Settings = serde_yaml::from_str(include_str!(
+            "../examples/example_settings.yaml"
+        )).unwrap();
+
+        settings.textures = vec![TextureElement {
+            name: "texture".to_string(),
+            path: "texture.png".to_string()
+        }];
+
+        let mut now = Instant::now();
+
+        let mut watcher = SettingsWatcher::new(
+            settings_file.to_str().unwrap(),
+            &settings,
+            now
+        );
+
+        now += CHECK_INTERVAL;
+        assert!(!watcher.check(now));
+
+        let modified = SystemTime::now() + Duration::from_secs(10);
+        File::options().write(true).open(&texture_file).unwrap()
+            .set_modified(modified).unwrap();
+
+        // too early.
+        assert!(!watcher.check(now));
+
+        now += CHECK_INTERVAL;
+        assert!(watcher.check(now));
+
+        now += CHECK_INTERVAL;
+        assert!(!watcher.check(now));
+
+        fs::remove_file(&settings_file).unwrap();
+
+        now += CHECK_INTERVAL;
+        assert!(watcher.check(now));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
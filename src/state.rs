@@ -1,16 +1,24 @@
+use crate::time_zone::ClockZone;
+
 use std::{
     cmp::{Ordering, PartialEq, Eq, PartialOrd, Ord},
-    path::PathBuf
+    path::PathBuf,
+    time::{Duration, Instant}
 };
 
 use chrono::{
-    Local,
-    Timelike
+    DateTime,
+    Utc,
+    Timelike,
+    Datelike,
+    NaiveDate,
+    Weekday
 };
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WatchMode {
     Stopwatch(StopwatchMode),
+    Timer(TimerMode),
     Clock
 }
 
@@ -20,12 +28,49 @@ pub enum StopwatchMode {
     Stopped
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimerMode {
+    Running,
+    Stopped,
+    Expired
+}
+
+/// What the stopwatch does when it reaches `stopwatch_limit`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OverflowPolicy {
+    /// Goes back to zero and keeps running.
+    #[default]
+    Wrap,
+
+    /// Keeps running, but the display stays just before the limit.
+    Saturate,
+
+    /// Stops just before the limit.
+    Stop
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ClockFormat {
+    #[default]
+    H24,
+    H12
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Button {
     Switch,
     StartStop,
     Reset,
-    Quit
+    Lap,
+    Quit,
+    NextSkin,
+    PrevSkin,
+    SelectSkin(u64),
+    ClearSkin,
+    ToggleAlwaysOnTop,
+    Minimize,
+    DismissAlarm,
+    SnoozeAlarm
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -36,6 +81,14 @@ pub struct WatchTime {
     pub cents: u32
 }
 
+/// Default `stopwatch_limit`. (100 hours)
+pub const DEFAULT_STOPWATCH_LIMIT: WatchTime = WatchTime {
+    hours: 100,
+    minutes: 0,
+    seconds: 0,
+    cents: 0
+};
+
 impl WatchTime {
     #[inline]
     pub fn to_u128(&self) -> u128 {
@@ -49,6 +102,48 @@ impl WatchTime {
             | (self.cents as u128)
     }
 
+    #[inline]
+    pub fn to_cents(&self) -> u64 {
+        (self.hours as u64 * 360000)
+            + (self.minutes as u64 * 6000)
+            + (self.seconds as u64 * 100)
+            + (self.cents as u64)
+    }
+
+    #[inline]
+    pub fn from_cents(cents: u64) -> Self {
+        let mut ret = Self {
+            hours: (cents / 360000) as u32,
+            minutes: 0,
+            seconds: 0,
+            cents: (cents % 360000) as u32
+        };
+
+        ret.normalize();
+
+        ret
+    }
+
+    #[inline]
+    pub fn from_duration(duration: Duration) -> Self {
+        Self::from_cents((duration.as_millis() / 10) as u64)
+    }
+
+    #[inline]
+    pub fn to_duration(&self) -> Duration {
+        Duration::from_millis(self.to_cents() * 10)
+    }
+
+    #[inline]
+    pub fn from_clock<T: Timelike>(time: &T) -> Self {
+        Self {
+            hours: time.hour(),
+            minutes: time.minute(),
+            seconds: time.second(),
+            cents: time.nanosecond() / 10000000
+        }
+    }
+
     #[inline]
     pub fn normalize(&mut self) {
         self.seconds += self.cents / 100;
@@ -58,7 +153,6 @@ impl WatchTime {
         self.cents %= 100;
         self.minutes %= 60;
         self.seconds %= 60;
-        self.hours %= 100;
     }
 }
 
@@ -67,7 +161,7 @@ impl Eq for WatchTime {}
 impl PartialOrd for WatchTime {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.to_u128().partial_cmp(&other.to_u128())
+        Some(self.cmp(other))
     }
 }
 
@@ -83,6 +177,267 @@ pub struct SkinSwitchEvent {
     pub skin_id: u64, 
 
     pub from_time: WatchTime,
+
+    /// Used only by clock events.
+    pub date_condition: DateCondition
+}
+
+/// A phase of the interval timer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimerPhase {
+    pub duration: WatchTime,
+
+    /// Used instead of the default timer skin in this phase.
+    pub skin_id: Option<u64>
+}
+
+/// An alarm of clock mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alarm {
+    pub time: WatchTime,
+
+    /// Empty means every day.
+    pub weekdays: Vec<Weekday>,
+
+    /// Shown while ringing.
+    pub skin_id: Option<u64>,
+
+    /// Played once when the alarm starts ringing.
+    pub sound: Option<PathBuf>
+}
+
+impl Alarm {
+    #[inline]
+    fn is_enabled_on(&self, date: &NaiveDate) -> bool {
+        self.weekdays.is_empty() || self.weekdays.contains(&date.weekday())
+    }
+
+    /// Returns true if the alarm time is in `(from, to]`.
+    /// `to` must be less than one day after `from`.
+    pub fn is_due(
+        &self,
+        from: &(NaiveDate, WatchTime),
+        to: &(NaiveDate, WatchTime)
+    ) -> bool {
+        if from.0 == to.0 {
+            self.is_enabled_on(&to.0)
+                && from.1 < self.time
+                && self.time <= to.1
+        } else {
+            (self.is_enabled_on(&from.0) && from.1 < self.time)
+                || (self.is_enabled_on(&to.0) && self.time <= to.1)
+        }
+    }
+}
+
+/// Index is of `State::alarms`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AlarmState {
+    #[default]
+    Idle,
+    Ringing(usize),
+    Snoozed {index: usize, until: Instant}
+}
+
+impl AlarmState {
+    /// Index of the ringing or snoozed alarm.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            Self::Idle => None,
+            Self::Ringing(index) => Some(*index),
+            Self::Snoozed {index, ..} => Some(*index)
+        }
+    }
+}
+
+/// A date. If `year` is None, it matches the date of every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventDate {
+    pub year: Option<i32>,
+    pub month: u32,
+    pub day: u32
+}
+
+impl EventDate {
+    #[inline]
+    pub fn matches(&self, date: &NaiveDate) -> bool {
+        self.year.map(|year| year == date.year()).unwrap_or(true)
+            && self.month == date.month()
+            && self.day == date.day()
+    }
+
+    #[inline]
+    fn month_day(&self) -> (u32, u32) {
+        (self.month, self.day)
+    }
+}
+
+/// Days when a clock event is enabled.
+///
+/// Every condition which isn't empty must match.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DateCondition {
+    pub weekdays: Vec<Weekday>,
+
+    /// Inclusive. Both have a year or neither has.
+    /// Without years, the range can go over the new year. (e.g. Dec 20 - Jan 5)
+    pub date_range: Option<(EventDate, EventDate)>,
+
+    pub dates: Vec<EventDate>
+}
+
+impl DateCondition {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.weekdays.is_empty()
+            && self.date_range.is_none()
+            && self.dates.is_empty()
+    }
+
+    pub fn matches(&self, date: &NaiveDate) -> bool {
+        let weekday_matches = self.weekdays.is_empty()
+            || self.weekdays.contains(&date.weekday());
+
+        let date_range_matches = match self.date_range.as_ref() {
+            Some((from, to)) => Self::in_range(from, to, date),
+            None => true
+        };
+
+        let dates_match = self.dates.is_empty()
+            || self.dates.iter().any(|event_date| event_date.matches(date));
+
+        weekday_matches && date_range_matches && dates_match
+    }
+
+    fn in_range(from: &EventDate, to: &EventDate, date: &NaiveDate) -> bool {
+        match (from.year, to.year) {
+            (Some(from_year), Some(to_year)) => {
+                let from = (from_year, from.month, from.day);
+                let to = (to_year, to.month, to.day);
+                let date = (date.year(), date.month(), date.day());
+
+                from <= date && date <= to
+            },
+
+            _ => {
+                let from = from.month_day();
+                let to = to.month_day();
+                let date = (date.month(), date.day());
+
+                if from <= to {
+                    from <= date && date <= to
+                } else {
+                    from <= date || date <= to
+                }
+            }
+        }
+    }
+}
+
+/// Measures elapsed time with monotonic instants.
+///
+/// While running, elapsed time is `accumulated + (now - started_at)`,
+/// so it never drifts regardless of how often it is read.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ElapsedTime {
+    started_at: Option<Instant>,
+    accumulated: Duration
+}
+
+impl ElapsedTime {
+    #[inline]
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(accumulated: Duration) -> Self {
+        Self {
+            started_at: None,
+            accumulated: accumulated
+        }
+    }
+
+    #[inline]
+    pub fn start(&mut self, now: Instant) {
+        self.started_at.get_or_insert(now);
+    }
+
+    #[inline]
+    pub fn stop(&mut self, now: Instant) {
+        self.accumulated = self.elapsed(now);
+        self.started_at = None;
+    }
+
+    /// Sets elapsed time to zero. Keeps running if it is running.
+    #[inline]
+    pub fn reset(&mut self, now: Instant) {
+        self.set_elapsed(now, Duration::ZERO);
+    }
+
+    /// Sets elapsed time. Keeps running if it is running.
+    #[inline]
+    pub fn set_elapsed(&mut self, now: Instant, elapsed: Duration) {
+        self.accumulated = elapsed;
+
+        if self.started_at.is_some() {
+            self.started_at = Some(now);
+        }
+    }
+
+    #[inline]
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        match self.started_at {
+            Some(started_at) =>
+                self.accumulated + now.saturating_duration_since(started_at),
+
+            None => self.accumulated
+        }
+    }
+}
+
+/// Skins chosen by the user for each watch mode.
+///
+/// While a skin is chosen, it is used instead of skins of events.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SkinOverrides {
+    pub stopwatch: Option<u64>,
+    pub timer: Option<u64>,
+    pub clock: Option<u64>
+}
+
+impl SkinOverrides {
+    #[inline]
+    pub fn get(&self, watch_mode: WatchMode) -> Option<u64> {
+        match watch_mode {
+            WatchMode::Stopwatch(..) => self.stopwatch,
+            WatchMode::Timer(..) => self.timer,
+            WatchMode::Clock => self.clock
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, watch_mode: WatchMode) -> &mut Option<u64> {
+        match watch_mode {
+            WatchMode::Stopwatch(..) => &mut self.stopwatch,
+            WatchMode::Timer(..) => &mut self.timer,
+            WatchMode::Clock => &mut self.clock
+        }
+    }
+
+    /// Clears skins which are not in `skin_ids`.
+    pub fn retain(&mut self, skin_ids: &[u64]) {
+        for skin_id in [&mut self.stopwatch, &mut self.timer, &mut self.clock] {
+            if let Some(id) = skin_id {
+                if !skin_ids.contains(id) {
+                    *skin_id = None;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LapTime {
+    pub split: WatchTime,
+    pub lap: WatchTime
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -90,53 +445,136 @@ pub struct State {
     pub watch_mode: WatchMode,
     pub clicked_btn: Option<Button>,
     pub dt: f32,
+    pub now: Instant,
     pub button_is_pressed: bool,
 
     pub quit_request: bool,
+    pub save_request: bool,
+    pub minimize_request: bool,
+    pub reload_request: bool,
+
+    pub always_on_top: bool,
 
     pub current_stopwatch_time: WatchTime,
+    pub current_timer_time: WatchTime,
+    // always 24-hour. `clock_format` is applied only to the display.
     pub current_clock_time: WatchTime,
+    pub current_date: NaiveDate,
+    pub current_utc: DateTime<Utc>,
+    pub clock_format: ClockFormat,
+    pub clock_zone: ClockZone,
 
     pub change_skin_request: bool,
     pub current_skin_id: u64,
 
-    pub saved_time: f32,
+    // in the order of `skins` in settings file.
+    pub skin_ids: Vec<u64>,
+    pub skin_overrides: SkinOverrides,
+
+    pub stopwatch_elapsed: ElapsedTime,
+    pub stopwatch_limit: WatchTime,
+    pub stopwatch_overflow: OverflowPolicy,
+    pub resume_running: bool,
+
+    pub laps: Vec<LapTime>,
+
+    pub timer_elapsed: ElapsedTime,
+    // duration of the current phase if `timer_phases` is not empty.
+    pub timer_duration: Option<WatchTime>,
+    pub timer_phases: Vec<TimerPhase>,
+    pub timer_phase: usize,
+    pub timer_phases_loop: bool,
 
     pub default_stopwatch_skin_id: u64,
+    pub default_timer_skin_id: u64,
     pub default_clock_skin_id: u64,
 
+    pub timer_expired_skin_id: Option<u64>,
+
     pub stopwatch_events: Vec<SkinSwitchEvent>,
+    pub timer_events: Vec<SkinSwitchEvent>,
     pub clock_events: Vec<SkinSwitchEvent>,
 
-    pub save_data_path: PathBuf
+    pub alarms: Vec<Alarm>,
+    pub alarm_state: AlarmState,
+    pub alarm_snooze: Duration,
+    // the last date and time when alarms were checked.
+    pub alarm_checked_at: Option<(NaiveDate, WatchTime)>,
+    pub alarm_sound_request: Option<PathBuf>,
+    pub alarm_stop_request: bool,
+
+    pub save_data_path: PathBuf,
+    pub autosave_interval: Option<Duration>,
+    pub last_save_time: Instant
 }
 
 impl Default for State {
     #[inline]
+    #[allow(clippy::redundant_field_names)]
     fn default() -> Self {
+        let now = Instant::now();
+
         Self {
             watch_mode: WatchMode::Clock,
             clicked_btn: None,
             dt: Default::default(),
+            now: now,
             button_is_pressed: false,
 
             quit_request: false,
+            save_request: false,
+            minimize_request: false,
+            reload_request: false,
+
+            always_on_top: true,
 
             current_stopwatch_time: Default::default(),
+            current_timer_time: Default::default(),
             current_clock_time: Default::default(),
+            current_date: Default::default(),
+            current_utc: Default::default(),
+            clock_format: ClockFormat::H24,
+            clock_zone: ClockZone::Local,
 
             change_skin_request: false,
             current_skin_id: Default::default(),
 
-            saved_time: Default::default(),
+            skin_ids: Vec::<u64>::new(),
+            skin_overrides: Default::default(),
+
+            stopwatch_elapsed: Default::default(),
+            stopwatch_limit: DEFAULT_STOPWATCH_LIMIT,
+            stopwatch_overflow: OverflowPolicy::Wrap,
+            resume_running: false,
+
+            laps: Vec::<LapTime>::new(),
+
+            timer_elapsed: Default::default(),
+            timer_duration: None,
+            timer_phases: Vec::<TimerPhase>::new(),
+            timer_phase: 0,
+            timer_phases_loop: false,
 
             default_stopwatch_skin_id: Default::default(),
+            default_timer_skin_id: Default::default(),
             default_clock_skin_id: Default::default(),
 
+            timer_expired_skin_id: None,
+
             stopwatch_events: Vec::<SkinSwitchEvent>::new(),
+            timer_events: Vec::<SkinSwitchEvent>::new(),
             clock_events: Vec::<SkinSwitchEvent>::new(),
 
-            save_data_path: PathBuf::new()
+            alarms: Vec::<Alarm>::new(),
+            alarm_state: AlarmState::Idle,
+            alarm_snooze: Duration::from_secs(300),
+            alarm_checked_at: None,
+            alarm_sound_request: None,
+            alarm_stop_request: false,
+
+            save_data_path: PathBuf::new(),
+            autosave_interval: None,
+            last_save_time: now
         }
     }
 }
@@ -147,6 +585,10 @@ impl State {
             |event| event.from_time.normalize()
         );
 
+        self.timer_events.iter_mut().for_each(
+            |event| event.from_time.normalize()
+        );
+
         self.clock_events.iter_mut().for_each(
             |event| event.from_time.normalize()
         );
@@ -155,11 +597,37 @@ impl State {
             |a, b| b.from_time.partial_cmp(&a.from_time).unwrap()
         );
 
+        // timer counts down, so the smallest threshold comes first.
+        self.timer_events.sort_by(
+            |a, b| a.from_time.partial_cmp(&b.from_time).unwrap()
+        );
+
         self.clock_events.sort_by(
             |a, b| b.from_time.partial_cmp(&a.from_time).unwrap()
         );
     }
 
+    /// Returns the skin after the current one in `skin_ids`. (wraps around)
+    pub fn next_skin_id(&self) -> Option<u64> {
+        let index = self.skin_ids.iter().position(
+            |skin_id| *skin_id == self.current_skin_id
+        ).map(|index| index + 1).unwrap_or(0);
+
+        self.skin_ids.get(index).or_else(|| self.skin_ids.first()).copied()
+    }
+
+    /// Returns the skin before the current one in `skin_ids`. (wraps around)
+    pub fn prev_skin_id(&self) -> Option<u64> {
+        let index = self.skin_ids.iter().position(
+            |skin_id| *skin_id == self.current_skin_id
+        ).unwrap_or(0);
+
+        match index.checked_sub(1) {
+            Some(index) => self.skin_ids.get(index),
+            None => self.skin_ids.last()
+        }.copied()
+    }
+
     #[inline]
     pub fn find_skin_id_from_stopwatch_events(&self) -> u64 {
         if let Some(event) = self.stopwatch_events.iter().find(
@@ -171,10 +639,59 @@ impl State {
         }
     }
 
+    #[inline]
+    pub fn find_skin_id_from_timer_events(&self) -> u64 {
+        if let WatchMode::Timer(TimerMode::Expired) = self.watch_mode {
+            if let Some(skin_id) = self.timer_expired_skin_id {
+                return skin_id;
+            }
+        }
+
+        if let Some(event) = self.timer_events.iter().find(
+            |event| self.current_timer_time <= event.from_time
+        ) {
+            event.skin_id
+        } else {
+            self.timer_phases.get(self.timer_phase)
+                .and_then(|phase| phase.skin_id)
+                .unwrap_or(self.default_timer_skin_id)
+        }
+    }
+
+    /// Returns the index of the first alarm due since `from`.
+    pub fn find_due_alarm(
+        &self,
+        from: &(NaiveDate, WatchTime)
+    ) -> Option<usize> {
+        let to = (self.current_date, self.current_clock_time.clone());
+
+        self.alarms.iter().position(|alarm| alarm.is_due(from, &to))
+    }
+
+    /// Skin of the ringing alarm.
+    #[inline]
+    pub fn find_skin_id_from_alarm(&self) -> Option<u64> {
+        match self.alarm_state {
+            AlarmState::Ringing(index) =>
+                self.alarms.get(index).and_then(|alarm| alarm.skin_id),
+
+            _ => None
+        }
+    }
+
     #[inline]
     pub fn find_skin_id_from_clock_events(&self) -> u64 {
-        if let Some(event) = self.clock_events.iter().find(
-            |event| self.current_clock_time >= event.from_time
+        let is_started = |event: &&SkinSwitchEvent| {
+            self.current_clock_time >= event.from_time
+                && event.date_condition.matches(&self.current_date)
+        };
+
+        // once an event with date conditions starts,
+        // it takes precedence for the rest of the day.
+        if let Some(event) = self.clock_events.iter().filter(
+            |event| !event.date_condition.is_empty()
+        ).find(is_started).or_else(
+            || self.clock_events.iter().find(is_started)
         ) {
             event.skin_id
         } else {
@@ -182,26 +699,159 @@ impl State {
         }
     }
 
-    pub fn add_stopwatch_time(&mut self) {
-        self.saved_time += self.dt;
+    /// Returns `true` if the stopwatch has reached `stopwatch_limit`
+    /// with `OverflowPolicy::Stop`.
+    #[inline]
+    pub fn update_stopwatch_time(&mut self) -> bool {
+        let elapsed = WatchTime::from_duration(
+            self.stopwatch_elapsed.elapsed(self.now)
+        ).to_cents();
 
-        self.current_stopwatch_time.cents = (self.saved_time * 100.0) as u32;
-        self.current_stopwatch_time.normalize();
+        let limit = self.stopwatch_limit.to_cents();
 
-        self.saved_time = self.saved_time.fract();
+        let (cents, overflowed) = if elapsed < limit {
+            (elapsed, false)
+        } else {
+            match self.stopwatch_overflow {
+                OverflowPolicy::Wrap => (elapsed % limit, false),
+                OverflowPolicy::Saturate => (limit - 1, false),
+                OverflowPolicy::Stop => (limit - 1, true)
+            }
+        };
+
+        self.current_stopwatch_time = WatchTime::from_cents(cents);
+
+        overflowed
+    }
+
+    #[inline]
+    pub fn timer_is_enabled(&self) -> bool {
+        self.timer_duration.is_some()
+    }
+
+    #[inline]
+    pub fn timer_remaining(&self) -> Duration {
+        let duration = match self.timer_duration.as_ref() {
+            Some(duration) => duration.to_duration(),
+            None => Duration::ZERO
+        };
+
+        duration.saturating_sub(self.timer_elapsed.elapsed(self.now))
+    }
+
+    /// Returns `true` if the timer has reached zero.
+    pub fn update_timer_time(&mut self) -> bool {
+        let remaining = self.timer_remaining();
+
+        // rounds up so that the display reaches zero just when it expires.
+        self.current_timer_time = WatchTime::from_cents(
+            remaining.as_nanos().div_ceil(10_000_000) as u64
+        );
+
+        remaining.is_zero()
+    }
+
+    /// Goes back to the first phase if the timer has phases.
+    #[inline]
+    pub fn reset_timer(&mut self) {
+        self.set_timer_phase(0);
+        self.timer_elapsed.reset(self.now);
+        self.update_timer_time();
+    }
+
+    /// Sets the current phase and its duration.
+    /// Does nothing if `index` is out of `timer_phases`.
+    #[inline]
+    pub fn set_timer_phase(&mut self, index: usize) {
+        if let Some(phase) = self.timer_phases.get(index) {
+            self.timer_phase = index;
+            self.timer_duration = Some(phase.duration.clone());
+        }
+    }
+
+    /// Goes to the next phase after the current phase has ended.
+    /// Time over the end of the phase is carried into the next phase.
+    ///
+    /// Returns `false` if there is no next phase.
+    pub fn next_timer_phase(&mut self) -> bool {
+        let next = if self.timer_phase + 1 < self.timer_phases.len() {
+            self.timer_phase + 1
+        } else if self.timer_phases_loop && !self.timer_phases.is_empty() {
+            0
+        } else {
+            return false;
+        };
+
+        let over = self.timer_elapsed.elapsed(self.now).saturating_sub(
+            self.timer_duration.as_ref().map(WatchTime::to_duration)
+                .unwrap_or_default()
+        );
+
+        self.set_timer_phase(next);
+        self.timer_elapsed.set_elapsed(self.now, over);
+        self.update_timer_time();
+
+        true
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    pub fn record_lap(&mut self) {
+        let split = self.current_stopwatch_time.clone();
+
+        let prev_cents = match self.laps.last() {
+            Some(prev) => prev.split.to_cents(),
+            None => 0
+        };
+
+        let lap = WatchTime::from_cents(
+            split.to_cents().saturating_sub(prev_cents)
+        );
+
+        self.laps.push(LapTime {
+            split: split,
+            lap: lap
+        });
+    }
+
+    /// Clock time in `clock_format`. Hours are 1 to 12 in 12-hour format.
+    #[inline]
+    pub fn clock_display_time(&self) -> WatchTime {
+        self.format_clock_time(&self.current_clock_time)
+    }
+
+    /// `time` of 24-hour clock in `clock_format`.
+    #[inline]
+    pub fn format_clock_time(&self, time: &WatchTime) -> WatchTime {
+        let mut ret = time.clone();
+
+        if self.clock_format == ClockFormat::H12 {
+            ret.hours = match ret.hours % 12 {
+                0 => 12,
+                hours => hours
+            };
+        }
+
+        ret
+    }
+
+    #[inline]
+    pub fn clock_is_pm(&self) -> bool {
+        self.current_clock_time.hours >= 12
     }
 
     #[inline]
     pub fn set_clock(&mut self) {
-        let time = Local::now();
-        self.current_clock_time.hours = time.hour();
-        self.current_clock_time.minutes = time.minute();
-        self.current_clock_time.seconds = time.second();
-        self.current_clock_time.cents = time.nanosecond() / 10000000;
+        let now = Utc::now();
+        let time = self.clock_zone.local_time(&now);
+
+        self.current_clock_time = WatchTime::from_clock(&time);
+        self.current_date = time.date();
+        self.current_utc = now;
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
 
@@ -278,36 +928,155 @@ mod tests {
         let mut state =
             gen_state(WatchMode::Stopwatch(StopwatchMode::Running));
 
-        state.dt = 0.01;
+        state.stopwatch_elapsed.start(state.now);
+
         for _ in 0..321 {
-            state.add_stopwatch_time();
+            state.now += Duration::from_millis(10);
+            state.update_stopwatch_time();
         }
 
         assert_eq!(state.current_stopwatch_time.seconds, 3);
-        assert_eq!(state.current_stopwatch_time.cents, 20);
-        // for error less than 0.1 second.
-        assert!(
-            (state.current_stopwatch_time.cents >= 20)
-                && (state.current_stopwatch_time.cents <= 22)
-        );
+        assert_eq!(state.current_stopwatch_time.cents, 21);
 
-        state.dt = 1.0;
         for _ in 0..((60 * 3) + 21) {
-            state.add_stopwatch_time();
+            state.now += Duration::from_secs(1);
+            state.update_stopwatch_time();
         }
 
         assert_eq!(state.current_stopwatch_time.minutes, 3);
         assert_eq!(state.current_stopwatch_time.seconds, 21 + 3);
 
-        state.dt = 60.0;
         for _ in 0..((60 * 3) + 21) {
-            state.add_stopwatch_time();
+            state.now += Duration::from_secs(60);
+            state.update_stopwatch_time();
         }
 
         assert_eq!(state.current_stopwatch_time.hours, 3);
         assert_eq!(state.current_stopwatch_time.minutes, 21 + 3);
     }
 
+    #[test]
+    fn stopwatch_overflow_test() {
+        let mut state =
+            gen_state(WatchMode::Stopwatch(StopwatchMode::Running));
+
+        state.stopwatch_elapsed.start(state.now);
+        state.now += Duration::from_secs((123 * 60 * 60) + 45);
+
+        // 100 hours by default.
+        assert!(!state.update_stopwatch_time());
+        assert_eq!(state.current_stopwatch_time, WatchTime {
+            hours: 23,
+            minutes: 0,
+            seconds: 45,
+            cents: 0
+        });
+
+        state.stopwatch_limit.hours = 1000;
+        assert!(!state.update_stopwatch_time());
+        assert_eq!(state.current_stopwatch_time.hours, 123);
+
+        state.stopwatch_limit.hours = 100;
+        state.stopwatch_overflow = OverflowPolicy::Saturate;
+        assert!(!state.update_stopwatch_time());
+        assert_eq!(state.current_stopwatch_time, WatchTime {
+            hours: 99,
+            minutes: 59,
+            seconds: 59,
+            cents: 99
+        });
+
+        state.stopwatch_overflow = OverflowPolicy::Stop;
+        assert!(state.update_stopwatch_time());
+        assert_eq!(state.current_stopwatch_time.hours, 99);
+    }
+
+    // pseudo random frame time between 1 ms and 50 ms.
+    fn next_frame_time(seed: &mut u64) -> Duration {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        Duration::from_micros(1000 + ((*seed >> 33) % 49000))
+    }
+
+    #[test]
+    fn stopwatch_drift_test() {
+        let mut state =
+            gen_state(WatchMode::Stopwatch(StopwatchMode::Running));
+
+        let mut seed: u64 = 12345;
+        let mut wall_clock = Duration::ZERO;
+
+        state.stopwatch_elapsed.start(state.now);
+
+        // 5 hours, with a stall of 10 seconds every 10 minutes
+        // as if the window was not repainted.
+        let end = Duration::from_secs(5 * 60 * 60);
+        let mut next_stall = Duration::from_secs(10 * 60);
+
+        while wall_clock < end {
+            let dt = if wall_clock >= next_stall {
+                next_stall += Duration::from_secs(10 * 60);
+                Duration::from_secs(10)
+            } else {
+                next_frame_time(&mut seed)
+            };
+
+            state.now += dt;
+            wall_clock += dt;
+
+            state.update_stopwatch_time();
+
+            let diff = wall_clock.abs_diff(
+                state.current_stopwatch_time.to_duration()
+            );
+            assert!(diff <= Duration::from_millis(10));
+        }
+
+        assert_eq!(state.current_stopwatch_time.hours, 5);
+    }
+
+    #[test]
+    fn stopwatch_drift_test_2() {
+        let mut state =
+            gen_state(WatchMode::Stopwatch(StopwatchMode::Running));
+
+        let mut seed: u64 = 54321;
+        let mut running_time = Duration::ZERO;
+
+        // runs and stops alternately every 15 minutes for 3 hours.
+        for i in 0..12 {
+            let is_running = (i % 2) == 0;
+
+            if is_running {
+                state.stopwatch_elapsed.start(state.now);
+            } else {
+                state.stopwatch_elapsed.stop(state.now);
+            }
+
+            let mut period = Duration::ZERO;
+
+            while period < Duration::from_secs(15 * 60) {
+                let dt = next_frame_time(&mut seed);
+
+                state.now += dt;
+                period += dt;
+
+                if is_running {
+                    running_time += dt;
+                }
+
+                state.update_stopwatch_time();
+            }
+
+            let diff = running_time.abs_diff(
+                state.current_stopwatch_time.to_duration()
+            );
+            assert!(diff <= Duration::from_millis(10));
+        }
+
+        assert_eq!(state.current_stopwatch_time.hours, 1);
+        assert_eq!(state.current_stopwatch_time.minutes, 30);
+    }
+
     fn gen_stopwatch_events() -> Vec<SkinSwitchEvent> {
         let mut ret = Vec::<SkinSwitchEvent>::with_capacity(10);
 
@@ -321,7 +1090,9 @@ mod tests {
                         minutes: i as u32,
                         seconds: 0,
                         cents: 0
-                    }
+                    },
+
+                    date_condition: Default::default()
                 }
             );
         }
@@ -329,6 +1100,51 @@ mod tests {
         ret
     }
 
+    #[test]
+    fn timer_test() {
+        let mut state = gen_state(WatchMode::Timer(TimerMode::Running));
+
+        state.timer_duration = Some(WatchTime {
+            hours: 0,
+            minutes: 1,
+            seconds: 0,
+            cents: 0
+        });
+        state.reset_timer();
+        state.timer_elapsed.start(state.now);
+
+        for _ in 0..30 {
+            state.now += Duration::from_secs(1);
+            assert!(!state.update_timer_time());
+        }
+
+        assert_eq!(state.current_timer_time.minutes, 0);
+        assert_eq!(state.current_timer_time.seconds, 30);
+
+        for _ in 0..1234 {
+            state.now += Duration::from_millis(10);
+            assert!(!state.update_timer_time());
+        }
+
+        assert_eq!(state.current_timer_time.seconds, 17);
+        assert_eq!(state.current_timer_time.cents, 66);
+
+        state.now += Duration::from_millis(17650);
+        assert!(!state.update_timer_time());
+        assert_eq!(state.current_timer_time.cents, 1);
+
+        state.now += Duration::from_millis(10);
+        assert!(state.update_timer_time());
+        assert_eq!(state.current_timer_time, WatchTime::default());
+
+        state.now += Duration::from_secs(10);
+        assert!(state.update_timer_time());
+        assert_eq!(state.current_timer_time, WatchTime::default());
+
+        state.reset_timer();
+        assert_eq!(state.current_timer_time.minutes, 1);
+    }
+
     fn gen_clock_events() -> Vec<SkinSwitchEvent> {
         let mut ret = Vec::<SkinSwitchEvent>::with_capacity(10);
 
@@ -342,7 +1158,9 @@ mod tests {
                         minutes: i as u32,
                         seconds: 0,
                         cents: 0
-                    }
+                    },
+
+                    date_condition: Default::default()
                 }
             );
         }
@@ -350,6 +1168,37 @@ mod tests {
         ret
     }
 
+    #[test]
+    fn timer_events_test() {
+        let mut state = gen_state(WatchMode::Timer(TimerMode::Running));
+
+        state.timer_events = gen_stopwatch_events();
+        state.init();
+
+        state.default_timer_skin_id = 3;
+
+        for i in 0u32..=11 {
+            state.current_timer_time.seconds = 30;
+            state.current_timer_time.minutes = i;
+
+            if i >= 10 {
+                assert_eq!(state.find_skin_id_from_timer_events(), 3);
+            } else {
+                assert_eq!(
+                    state.find_skin_id_from_timer_events(),
+                    ((i + 1) as u64) * 11
+                );
+            }
+        }
+
+        state.watch_mode = WatchMode::Timer(TimerMode::Expired);
+        state.current_timer_time = WatchTime::default();
+        assert_eq!(state.find_skin_id_from_timer_events(), 11);
+
+        state.timer_expired_skin_id = Some(5);
+        assert_eq!(state.find_skin_id_from_timer_events(), 5);
+    }
+
     #[test]
     fn events_test() {
         let mut state =
@@ -387,4 +1236,384 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn next_skin_id_test() {
+        let mut state = State::default();
+
+        assert_eq!(state.next_skin_id(), None);
+
+        state.skin_ids = vec![10, 20, 30];
+
+        state.current_skin_id = 10;
+        assert_eq!(state.next_skin_id(), Some(20));
+
+        state.current_skin_id = 30;
+        assert_eq!(state.next_skin_id(), Some(10));
+
+        state.current_skin_id = 99;
+        assert_eq!(state.next_skin_id(), Some(10));
+    }
+
+    #[test]
+    fn prev_skin_id_test() {
+        let mut state = State::default();
+
+        assert_eq!(state.prev_skin_id(), None);
+
+        state.skin_ids = vec![10, 20, 30];
+
+        state.current_skin_id = 20;
+        assert_eq!(state.prev_skin_id(), Some(10));
+
+        state.current_skin_id = 10;
+        assert_eq!(state.prev_skin_id(), Some(30));
+
+        state.current_skin_id = 99;
+        assert_eq!(state.prev_skin_id(), Some(30));
+    }
+
+    #[test]
+    fn skin_overrides_test() {
+        let mut overrides = SkinOverrides::default();
+
+        *overrides.get_mut(WatchMode::Stopwatch(StopwatchMode::Running)) =
+            Some(1);
+        *overrides.get_mut(WatchMode::Timer(TimerMode::Expired)) = Some(2);
+        *overrides.get_mut(WatchMode::Clock) = Some(3);
+
+        assert_eq!(
+            overrides.get(WatchMode::Stopwatch(StopwatchMode::Stopped)),
+            Some(1)
+        );
+        assert_eq!(
+            overrides.get(WatchMode::Timer(TimerMode::Running)),
+            Some(2)
+        );
+        assert_eq!(overrides.get(WatchMode::Clock), Some(3));
+
+        overrides.retain(&[1, 3]);
+
+        assert_eq!(overrides, SkinOverrides {
+            stopwatch: Some(1),
+            timer: None,
+            clock: Some(3)
+        });
+    }
+
+    fn gen_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn date_condition_test() {
+        let condition = DateCondition::default();
+        assert!(condition.matches(&gen_date(2024, 1, 1)));
+
+        // 2024-01-06 is Saturday.
+        let condition = DateCondition {
+            weekdays: vec![Weekday::Sat, Weekday::Sun],
+            date_range: None,
+            dates: Vec::<EventDate>::new()
+        };
+        assert!(condition.matches(&gen_date(2024, 1, 6)));
+        assert!(condition.matches(&gen_date(2024, 1, 7)));
+        assert!(!condition.matches(&gen_date(2024, 1, 8)));
+
+        // over the new year.
+        let condition = DateCondition {
+            weekdays: Vec::<Weekday>::new(),
+            date_range: Some((
+                EventDate {year: None, month: 12, day: 20},
+                EventDate {year: None, month: 1, day: 5}
+            )),
+            dates: Vec::<EventDate>::new()
+        };
+        assert!(!condition.matches(&gen_date(2024, 12, 19)));
+        assert!(condition.matches(&gen_date(2024, 12, 20)));
+        assert!(condition.matches(&gen_date(2025, 1, 5)));
+        assert!(!condition.matches(&gen_date(2025, 1, 6)));
+
+        let condition = DateCondition {
+            weekdays: Vec::<Weekday>::new(),
+            date_range: Some((
+                EventDate {year: Some(2024), month: 3, day: 1},
+                EventDate {year: Some(2024), month: 3, day: 31}
+            )),
+            dates: Vec::<EventDate>::new()
+        };
+        assert!(condition.matches(&gen_date(2024, 3, 15)));
+        assert!(!condition.matches(&gen_date(2025, 3, 15)));
+
+        // every condition must match.
+        let condition = DateCondition {
+            weekdays: vec![Weekday::Mon],
+            date_range: None,
+            dates: vec![
+                EventDate {year: None, month: 1, day: 1},
+                EventDate {year: Some(2024), month: 1, day: 8}
+            ]
+        };
+        assert!(condition.matches(&gen_date(2024, 1, 8)));
+        assert!(!condition.matches(&gen_date(2025, 1, 1)));
+        assert!(condition.matches(&gen_date(2029, 1, 1)));
+        assert!(!condition.matches(&gen_date(2024, 1, 15)));
+    }
+
+    #[test]
+    fn clock_date_events_test() {
+        let mut state = gen_state(WatchMode::Clock);
+
+        state.clock_events = gen_clock_events();
+        state.clock_events[4].date_condition.weekdays = vec![Weekday::Sat];
+
+        let mut event = state.clock_events[5].clone();
+        event.skin_id = 9999;
+        event.date_condition.dates = vec![EventDate {
+            year: None,
+            month: 1,
+            day: 1
+        }];
+        state.clock_events.push(event);
+
+        state.init();
+
+        state.default_clock_skin_id = 4;
+
+        state.current_clock_time.minutes = 5;
+        state.current_clock_time.seconds = 30;
+
+        state.current_date = gen_date(2024, 1, 6);
+        assert_eq!(state.find_skin_id_from_clock_events(), 5 * 111);
+
+        // falls back to the previous event.
+        state.current_date = gen_date(2024, 1, 8);
+        assert_eq!(state.find_skin_id_from_clock_events(), 4 * 111);
+
+        state.current_clock_time.minutes = 6;
+        assert_eq!(state.find_skin_id_from_clock_events(), 6 * 111);
+
+        // takes precedence over the event of the same time.
+        state.current_date = gen_date(2025, 1, 1);
+        assert_eq!(state.find_skin_id_from_clock_events(), 9999);
+
+        // and over later events for the rest of the day.
+        state.current_clock_time.minutes = 10;
+        assert_eq!(state.find_skin_id_from_clock_events(), 9999);
+
+        state.current_date = gen_date(2024, 1, 6);
+        state.current_clock_time.minutes = 8;
+        assert_eq!(state.find_skin_id_from_clock_events(), 5 * 111);
+
+        state.current_date = gen_date(2024, 1, 8);
+        assert_eq!(state.find_skin_id_from_clock_events(), 8 * 111);
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    fn gen_alarm(hours: u32, minutes: u32, weekdays: Vec<Weekday>) -> Alarm {
+        Alarm {
+            time: WatchTime {
+                hours: hours,
+                minutes: minutes,
+                seconds: 0,
+                cents: 0
+            },
+            weekdays: weekdays,
+            skin_id: None,
+            sound: None
+        }
+    }
+
+    #[test]
+    #[allow(clippy::redundant_field_names)]
+    fn alarm_test() {
+        let at = |date: NaiveDate, hours: u32, minutes: u32| (
+            date,
+            WatchTime {
+                hours: hours,
+                minutes: minutes,
+                seconds: 0,
+                cents: 0
+            }
+        );
+
+        // 2024-01-06 is Saturday.
+        let sat = gen_date(2024, 1, 6);
+        let sun = gen_date(2024, 1, 7);
+
+        let alarm = gen_alarm(7, 0, Vec::<Weekday>::new());
+        assert!(alarm.is_due(&at(sat, 6, 59), &at(sat, 7, 0)));
+        assert!(!alarm.is_due(&at(sat, 7, 0), &at(sat, 7, 1)));
+        assert!(!alarm.is_due(&at(sat, 6, 58), &at(sat, 6, 59)));
+
+        // over the midnight.
+        let alarm = gen_alarm(0, 0, vec![Weekday::Sun]);
+        assert!(alarm.is_due(&at(sat, 23, 59), &at(sun, 0, 0)));
+
+        let alarm = gen_alarm(23, 59, vec![Weekday::Sat]);
+        assert!(alarm.is_due(&at(sat, 23, 58), &at(sun, 0, 0)));
+        assert!(!alarm.is_due(&at(sat, 23, 59), &at(sun, 0, 0)));
+
+        let alarm = gen_alarm(7, 0, vec![Weekday::Mon]);
+        assert!(!alarm.is_due(&at(sat, 6, 59), &at(sat, 7, 0)));
+
+        let mut state = gen_state(WatchMode::Clock);
+        state.alarms = vec![
+            gen_alarm(7, 0, vec![Weekday::Mon]),
+            gen_alarm(7, 0, Vec::<Weekday>::new())
+        ];
+        state.alarms[1].skin_id = Some(1234);
+
+        state.current_date = sat;
+        state.current_clock_time = at(sat, 7, 0).1;
+        assert_eq!(state.find_due_alarm(&at(sat, 6, 59)), Some(1));
+        assert_eq!(state.find_due_alarm(&at(sat, 7, 0)), None);
+
+        assert_eq!(state.find_skin_id_from_alarm(), None);
+        state.alarm_state = AlarmState::Ringing(1);
+        assert_eq!(state.find_skin_id_from_alarm(), Some(1234));
+        state.alarm_state = AlarmState::Ringing(0);
+        assert_eq!(state.find_skin_id_from_alarm(), None);
+    }
+
+    #[allow(clippy::redundant_field_names)]
+    fn gen_timer_phase(seconds: u32, skin_id: Option<u64>) -> TimerPhase {
+        TimerPhase {
+            duration: WatchTime {
+                hours: 0,
+                minutes: 0,
+                seconds: seconds,
+                cents: 0
+            },
+            skin_id: skin_id
+        }
+    }
+
+    #[test]
+    fn timer_phases_test() {
+        let mut state = gen_state(WatchMode::Timer(TimerMode::Running));
+
+        state.default_timer_skin_id = 1;
+        state.timer_phases = vec![
+            gen_timer_phase(10, Some(2)),
+            gen_timer_phase(5, None)
+        ];
+
+        state.reset_timer();
+        state.timer_elapsed.start(state.now);
+
+        assert_eq!(state.current_timer_time.seconds, 10);
+        assert_eq!(state.find_skin_id_from_timer_events(), 2);
+
+        state.now += Duration::from_millis(10_250);
+        assert!(state.update_timer_time());
+
+        // 0.25 seconds over the end is carried.
+        assert!(state.next_timer_phase());
+        assert_eq!(state.timer_phase, 1);
+        assert_eq!(state.current_timer_time.seconds, 4);
+        assert_eq!(state.current_timer_time.cents, 75);
+        assert_eq!(state.find_skin_id_from_timer_events(), 1);
+
+        state.now += Duration::from_secs(5);
+        assert!(state.update_timer_time());
+        assert!(!state.next_timer_phase());
+        assert_eq!(state.timer_phase, 1);
+
+        state.timer_phases_loop = true;
+        assert!(state.next_timer_phase());
+        assert_eq!(state.timer_phase, 0);
+        assert_eq!(state.current_timer_time.seconds, 9);
+
+        state.now += Duration::from_secs(20);
+        state.reset_timer();
+        assert_eq!(state.timer_phase, 0);
+        assert_eq!(state.current_timer_time.seconds, 10);
+
+        // without phases.
+        state.timer_phases.clear();
+        assert!(!state.next_timer_phase());
+    }
+
+    #[test]
+    fn clock_format_test() {
+        let mut state = gen_state(WatchMode::Clock);
+
+        let hours = [
+            (0, 12, false),
+            (1, 1, false),
+            (11, 11, false),
+            (12, 12, true),
+            (13, 1, true),
+            (23, 11, true)
+        ];
+
+        for (hours_24, hours_12, is_pm) in hours {
+            state.current_clock_time.hours = hours_24;
+            state.current_clock_time.minutes = 34;
+
+            state.clock_format = ClockFormat::H24;
+            assert_eq!(state.clock_display_time(), state.current_clock_time);
+
+            state.clock_format = ClockFormat::H12;
+            assert_eq!(state.clock_display_time().hours, hours_12);
+            assert_eq!(state.clock_display_time().minutes, 34);
+            assert_eq!(state.clock_is_pm(), is_pm);
+
+            // events and alarms use 24-hour time.
+            assert_eq!(state.current_clock_time.hours, hours_24);
+        }
+    }
+
+    #[test]
+    fn watch_time_test_4() {
+        let wt = WatchTime {
+            hours: 12,
+            minutes: 34,
+            seconds: 56,
+            cents: 78
+        };
+
+        assert_eq!(wt.to_cents(), 4529678);
+        assert_eq!(WatchTime::from_cents(wt.to_cents()), wt);
+    }
+
+    #[test]
+    fn lap_test() {
+        let mut state =
+            gen_state(WatchMode::Stopwatch(StopwatchMode::Running));
+
+        state.current_stopwatch_time = WatchTime {
+            hours: 0,
+            minutes: 1,
+            seconds: 10,
+            cents: 50
+        };
+        state.record_lap();
+
+        state.current_stopwatch_time = WatchTime {
+            hours: 0,
+            minutes: 2,
+            seconds: 5,
+            cents: 20
+        };
+        state.record_lap();
+
+        assert_eq!(state.laps.len(), 2);
+
+        assert_eq!(state.laps[0].split, state.laps[0].lap);
+        assert_eq!(
+            state.laps[1].split,
+            state.current_stopwatch_time
+        );
+        assert_eq!(
+            state.laps[1].lap,
+            WatchTime {
+                hours: 0,
+                minutes: 0,
+                seconds: 54,
+                cents: 70
+            }
+        );
+    }
 }
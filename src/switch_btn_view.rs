@@ -1,4 +1,9 @@
-use crate::{error::Error, state::{State, Button, WatchMode}, view::View};
+use crate::{
+    error::Error,
+    state::{State, Button, WatchMode},
+    view::View,
+    button_view::ButtonState
+};
 
 use eframe::{
     egui::{
@@ -18,27 +23,43 @@ pub struct SwitchBtnView {
     texture_id: TextureId,
 
     region: Rect,
-    ani_value: ChobitAniValue
+    ani_value: ChobitAniValue,
+    states: usize,
+    button_state: ButtonState,
+
+    has_timer_frame: bool
 }
 
 const STOPWATCH_MODE_FRAME: usize = 0;
 const CLOCK_MODE_FRAME: usize = 1;
+const TIMER_MODE_FRAME: usize = 2;
 
 impl SwitchBtnView {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(
         depth: i32,
         texture_id: TextureId,
         region: Rect,
+        states: usize,
+        has_timer_frame: bool
     ) -> Self {
+        let frames = if has_timer_frame {3} else {2};
+
         Self {
             depth: depth,
 
             texture_id: texture_id,
 
             region: region,
-            ani_value: ChobitAniValue::new(2, &[2], 1.0).expect(
-                "Error at SwitchBtnView::new()"
-            )
+            ani_value: ChobitAniValue::new(
+                frames,
+                &vec![frames; states],
+                1.0
+            ).expect("Error at SwitchBtnView::new()"),
+            states: states,
+            button_state: ButtonState::Idle,
+
+            has_timer_frame: has_timer_frame
         }
     }
 }
@@ -52,23 +73,29 @@ impl View for SwitchBtnView {
             let pointer = &i_state.pointer;
 
             if let Some(pos) = pointer.interact_pos() {
-                if self.region.contains(pos) {
-                    if pointer.primary_clicked() {
-                        state.clicked_btn = Some(Button::Switch);
-                    }
+                if self.region.contains(pos) && pointer.primary_clicked() {
+                    state.clicked_btn = Some(Button::Switch);
                 }
             }
         });
 
+        self.button_state = ButtonState::from_pointer(ui, &self.region);
+
         Ok(())
     }
 
     fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
         let frame = match state.watch_mode {
             WatchMode::Stopwatch(..) => STOPWATCH_MODE_FRAME,
+            WatchMode::Timer(..) => if self.has_timer_frame {
+                TIMER_MODE_FRAME
+            } else {
+                STOPWATCH_MODE_FRAME
+            },
             WatchMode::Clock => CLOCK_MODE_FRAME
         };
 
+        self.ani_value.set_row(self.button_state.row(self.states));
         self.ani_value.set_frame(frame);
         let (left, top, right, bottom) =
             self.ani_value.uv_frame_left_top_right_bottom();
@@ -0,0 +1,163 @@
+use chrono::{
+    DateTime,
+    Duration as ChronoDuration,
+    Local,
+    NaiveDateTime,
+    Utc
+};
+
+use chrono_tz::Tz;
+
+/// Time zone of a clock.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ClockZone {
+    #[default]
+    Local,
+
+    /// Seconds east of UTC.
+    Fixed(i32),
+
+    /// IANA time zone from the bundled time zone database.
+    Tz(Tz)
+}
+
+impl ClockZone {
+    /// `name` is `"local"`, a fixed offset like `"+09:00"` or `"-05:30"`,
+    /// or an IANA name like `"Asia/Tokyo"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name == "local" {
+            return Some(Self::Local);
+        }
+
+        if name.starts_with(['+', '-']) {
+            return Self::parse_fixed_offset(name).map(Self::Fixed);
+        }
+
+        name.parse::<Tz>().ok().map(Self::Tz)
+    }
+
+    // "+09:00", "-05:30" or "+9"
+    fn parse_fixed_offset(name: &str) -> Option<i32> {
+        let (sign, rest) = name.split_at(1);
+        let sign = if sign == "-" {-1} else {1};
+
+        let mut parts = rest.split(':');
+
+        let hours = Self::parse_number(parts.next()?)?;
+        let minutes = match parts.next() {
+            Some(minutes) => Self::parse_number(minutes)?,
+            None => 0
+        };
+
+        if parts.next().is_some() || hours > 23 || minutes > 59 {
+            return None;
+        }
+
+        Some(sign * ((hours * 60 * 60) + (minutes * 60)))
+    }
+
+    fn parse_number(digits: &str) -> Option<i32> {
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        digits.parse::<i32>().ok()
+    }
+
+    /// Local date and time of this zone at `utc`.
+    pub fn local_time(&self, utc: &DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Self::Local => utc.with_timezone(&Local).naive_local(),
+
+            Self::Fixed(offset) =>
+                utc.naive_utc() + ChronoDuration::seconds(*offset as i64),
+
+            Self::Tz(tz) => utc.with_timezone(tz).naive_local()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn date_time(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32
+    ) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+            .and_hms_opt(hour, min, 0).unwrap()
+    }
+
+    fn utc(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32
+    ) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&date_time(year, month, day, hour, min))
+    }
+
+    #[test]
+    fn fixed_offset_test() {
+        assert_eq!(ClockZone::from_name("local"), Some(ClockZone::Local));
+        assert_eq!(
+            ClockZone::from_name("+09:00"),
+            Some(ClockZone::Fixed(9 * 60 * 60))
+        );
+        assert_eq!(
+            ClockZone::from_name("-05:30"),
+            Some(ClockZone::Fixed(-((5 * 60) + 30) * 60))
+        );
+        assert_eq!(ClockZone::from_name("+9"), Some(ClockZone::Fixed(32400)));
+        assert_eq!(ClockZone::from_name("+24:00"), None);
+        assert_eq!(ClockZone::from_name("+09:60"), None);
+        assert_eq!(ClockZone::from_name("+09:00:00"), None);
+        assert_eq!(ClockZone::from_name("+"), None);
+
+        let time = ClockZone::Fixed(9 * 60 * 60)
+            .local_time(&utc(2024, 12, 31, 20, 30));
+
+        assert_eq!(time, date_time(2025, 1, 1, 5, 30));
+    }
+
+    #[test]
+    fn tz_test() {
+        assert_eq!(
+            ClockZone::from_name("Asia/Tokyo"),
+            Some(ClockZone::Tz(Tz::Asia__Tokyo))
+        );
+        assert_eq!(ClockZone::from_name("Not/A_Zone"), None);
+        assert_eq!(ClockZone::from_name("../etc/passwd"), None);
+        assert_eq!(ClockZone::from_name("/etc/localtime"), None);
+
+        let zone = ClockZone::from_name("America/New_York").unwrap();
+
+        // DST starts at 2024-03-10 02:00 EST.
+        assert_eq!(
+            zone.local_time(&utc(2024, 3, 10, 6, 59)),
+            date_time(2024, 3, 10, 1, 59)
+        );
+        assert_eq!(
+            zone.local_time(&utc(2024, 3, 10, 7, 0)),
+            date_time(2024, 3, 10, 3, 0)
+        );
+
+        let zone = ClockZone::from_name("Australia/Sydney").unwrap();
+
+        // DST ends at 2024-04-07 03:00 AEDT.
+        assert_eq!(
+            zone.local_time(&utc(2024, 4, 6, 15, 59)),
+            date_time(2024, 4, 7, 2, 59)
+        );
+        assert_eq!(
+            zone.local_time(&utc(2024, 4, 6, 16, 0)),
+            date_time(2024, 4, 7, 2, 0)
+        );
+    }
+}
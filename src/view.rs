@@ -5,6 +5,7 @@ use eframe::egui::Ui;
 pub trait View {
     fn depth(&self) -> i32;
 
+    #[allow(dead_code)]
     fn init(&mut self, state: &mut State) -> Result<(), Error>;
 
     fn ready(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error>;
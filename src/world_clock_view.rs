@@ -0,0 +1,85 @@
+use crate::{
+    error::Error,
+    state::{State, WatchMode, WatchTime},
+    view::View,
+    display_view::DisplayView,
+    time_zone::ClockZone
+};
+
+use eframe::{
+    egui::{
+        Ui,
+        TextureId,
+        Rect,
+        Pos2,
+        Color32
+    }
+};
+
+/// Shows the time of another time zone in clock mode.
+pub struct WorldClockView {
+    depth: i32,
+
+    display: DisplayView,
+    zone: ClockZone,
+
+    // (texture, region)
+    label: Option<(TextureId, Rect)>
+}
+
+impl WorldClockView {
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(
+        depth: i32,
+        display: DisplayView,
+        zone: ClockZone,
+        label: Option<(TextureId, Rect)>
+    ) -> Self {
+        Self {
+            depth: depth,
+
+            display: display,
+            zone: zone,
+
+            label: label
+        }
+    }
+}
+
+impl View for WorldClockView {
+    #[inline]
+    fn depth(&self) -> i32 {self.depth}
+
+    fn ready(&mut self, _ui: &Ui, _state: &mut State) -> Result<(), Error> {
+        // nothing to do.
+        Ok(())
+    }
+
+    fn show(&mut self, ui: &Ui, state: &mut State) -> Result<(), Error> {
+        if state.watch_mode != WatchMode::Clock {
+            return Ok(());
+        }
+
+        let local_time = self.zone.local_time(&state.current_utc);
+        let time = WatchTime::from_clock(&local_time);
+
+        self.display.show_time(ui, &state.format_clock_time(&time))?;
+        self.display.show_date(ui, &local_time.date())?;
+
+        if let Some((texture_id, region)) = self.label {
+            ui.painter().image(
+                texture_id,
+                region,
+                Rect::from_min_max(Pos2::new(0.0, 0.0), Pos2::new(1.0, 1.0)),
+                Color32::WHITE
+            );
+        }
+
+        Ok(())
+    }
+
+    fn init(&mut self, _state: &mut State) -> Result<(), Error> {
+        // pass
+        Ok(())
+    }
+}